@@ -0,0 +1,68 @@
+use std::fmt;
+
+use rug::Integer;
+
+
+/// Answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    BigInt(Integer),
+    Str(String),
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(v) => write!(f, "{}", v),
+            Answer::BigInt(v) => write!(f, "{}", v),
+            Answer::Str(v) => write!(f, "{}", v),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+
+impl From<i32> for Answer {
+    fn from(v: i32) -> Answer { Answer::Int(v.into()) }
+}
+
+impl From<u32> for Answer {
+    fn from(v: u32) -> Answer { Answer::Int(v.into()) }
+}
+
+impl From<i64> for Answer {
+    fn from(v: i64) -> Answer { Answer::Int(v) }
+}
+
+/// Unsigned values only fall back to a big integer if they don't fit in an i64
+impl From<u64> for Answer {
+    fn from(v: u64) -> Answer {
+        match i64::try_from(v) {
+            Ok(v) => Answer::Int(v),
+            Err(_) => Answer::BigInt(Integer::from(v)),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(v: usize) -> Answer { Answer::from(v as u64) }
+}
+
+impl From<Integer> for Answer {
+    fn from(v: Integer) -> Answer {
+        match v.to_i64() {
+            Some(v) => Answer::Int(v),
+            None => Answer::BigInt(v),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(v: String) -> Answer { Answer::Str(v) }
+}
+
+impl From<&str> for Answer {
+    fn from(v: &str) -> Answer { Answer::Str(String::from(v)) }
+}
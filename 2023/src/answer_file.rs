@@ -0,0 +1,148 @@
+use std::{fs, io, path::{Path, PathBuf}};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+
+
+/// Checked-in record of known answers, one line per input file and part e.g.
+///
+/// ```text
+/// day17 2 1362
+/// ```
+///
+/// Example inputs instead keep their expected answers in a file alongside them, so
+/// `day17_test2.txt` has a `day17_test2.expected` with one line per part e.g.
+///
+/// ```text
+/// 2 71
+/// ```
+///
+/// An answer of `skip: <reason>` means the part isn't run on that input at all, e.g. an
+/// example which doesn't fit what the solution assumes about the real input.
+///
+/// Blank lines and lines starting with '#' are ignored
+pub struct AnswerFile {
+    path: PathBuf,
+    example: Option<String>,
+    records: Vec<Record>,
+}
+
+/// Known answer for one part of one input file
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+impl AnswerFile {
+    /// Load from disk - a missing file is treated as empty
+    pub fn load(path: &Path) -> AnswerFile {
+        let records = read_lines(path)
+            .iter()
+            .filter_map(|line| Record::from_string(line))
+            .collect();
+        AnswerFile{ path: path.to_path_buf(), example: None, records }
+    }
+
+    /// Load the expected answers for an example input - a missing file is treated as empty
+    pub fn load_expected(data_dir: &Path, input: &str) -> AnswerFile {
+        let path = data_dir.join(format!("{input}.expected"));
+        let records = read_lines(&path)
+            .iter()
+            .filter_map(|line| Record::from_string(&format!("{input} {line}")))
+            .collect();
+        AnswerFile{ path, example: Some(String::from(input)), records }
+    }
+
+    /// Known answer for this input and part
+    pub fn get(&self, input: &str, part: u8) -> Option<&str> {
+        self.records
+            .iter()
+            .find(|r| (r.input == input) && (r.part == part))
+            .map(|r| r.answer.as_str())
+    }
+
+    /// Record a new answer, replacing any existing one
+    pub fn set(&mut self, input: &str, part: u8, answer: &Answer) {
+        self.records.retain(|r| !((r.input == input) && (r.part == part)));
+        self.records.push(Record{ input: String::from(input), part, answer: answer.to_string() });
+        self.records.sort_by_key(|r| (day_of(&r.input), r.input.clone(), r.part));
+    }
+
+    /// Every recorded answer, grouped by input file
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let lines = match self.example {
+            Some(_) => self.records.iter().map(|r| format!("{} {}", r.part, r.answer)).collect_vec(),
+            None => {
+                let mut lines = vec![String::from("# input part answer")];
+                lines.extend(self.records.iter().map(|r| format!("{} {} {}", r.input, r.part, r.answer)));
+                lines
+            },
+        };
+        fs::write(&self.path, lines.join("\n") + "\n")
+    }
+}
+
+impl Record {
+    fn from_string(line: &str) -> Option<Record> {
+        let mut parts = line.splitn(3, ' ');
+        let input = parts.next()?;
+        let part = parts.next()?.parse().ok()?;
+        let answer = parts.next()?.trim();
+        Some(Record{ input: String::from(input), part, answer: String::from(answer) })
+    }
+}
+
+
+/// Why a part is skipped, if its recorded answer says it should be
+pub fn skip_reason(answer: &str) -> Option<&str> {
+    answer.strip_prefix("skip:").map(|reason| reason.trim())
+}
+
+
+/// Example inputs for a day i.e. `dayN_test*.txt` files, sorted by name
+pub fn examples(data_dir: &Path, day: u32) -> Vec<String> {
+    let prefix = format!("day{day}_test");
+    let Ok(entries) = fs::read_dir(data_dir) else { return vec![]; };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| name.strip_suffix(".txt").map(String::from))
+        .filter(|name| name.starts_with(&prefix))
+        .sorted()
+        .collect()
+}
+
+
+/// Is this an example input rather than the real puzzle input?
+pub fn is_example(input: &str) -> bool {
+    input.contains("_test")
+}
+
+
+/// Day number from an input name like "day17_test2"
+pub fn day_of(input: &str) -> Option<u32> {
+    input
+        .strip_prefix("day")?
+        .split('_')
+        .next()?
+        .parse()
+        .ok()
+}
+
+
+/// Non-empty lines which aren't comments
+fn read_lines(path: &Path) -> Vec<String> {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .split("\n")
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect()
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+/// Command line arguments, split into positional arguments and `--flags`
+///
+/// Flags listed as taking a value consume the next argument, e.g. `--runs 10`,
+/// and any flag can also be given as `--runs=10`. Single letter flags can be
+/// bunched together, so `-vv` is the same as `-v -v`.
+pub struct Args {
+    pub positional: Vec<String>,
+    flags: Vec<(String, Option<String>)>,
+}
+
+impl Args {
+    /// Parse arguments, given the names of flags which take a value
+    pub fn parse(args: impl IntoIterator<Item = String>, value_flags: &[&str]) -> Args {
+        let mut positional = vec![];
+        let mut flags = vec![];
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(name) = arg.strip_prefix("--") else {
+                match arg.strip_prefix('-').filter(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic())) {
+                    Some(letters) => flags.extend(letters.chars().map(|c| (c.to_string(), None))),
+                    None => positional.push(arg),
+                }
+                continue;
+            };
+            if let Some((name, value)) = name.split_once('=') {
+                flags.push((String::from(name), Some(String::from(value))));
+            } else if value_flags.contains(&name) {
+                flags.push((String::from(name), args.next()));
+            } else {
+                flags.push((String::from(name), None));
+            }
+        }
+        Args{ positional, flags }
+    }
+
+    /// Was this flag given?
+    pub fn has(&self, name: &str) -> bool {
+        self.flags.iter().any(|(n, _)| n == name)
+    }
+
+    /// Number of times a flag was given
+    pub fn count(&self, name: &str) -> usize {
+        self.flags.iter().filter(|(n, _)| n == name).count()
+    }
+
+    /// Every value given for a flag, in order
+    pub fn values(&self, name: &str) -> Vec<&str> {
+        self.flags.iter()
+            .filter(|(n, _)| n == name)
+            .filter_map(|(_, v)| v.as_deref())
+            .collect()
+    }
+
+    /// Value of a flag, if given more than once the last one wins
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.flags.iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .and_then(|(_, v)| v.as_deref())
+    }
+
+    /// Value of a flag parsed into some type, exiting with a message if it's not valid
+    pub fn parsed<T: std::str::FromStr>(&self, name: &str) -> Option<T> {
+        self.value(name).map(|v| match v.parse() {
+            Ok(v) => v,
+            Err(_) => {
+                eprintln!("Invalid value for --{}: {}", name, v);
+                std::process::exit(2);
+            },
+        })
+    }
+}
\ No newline at end of file
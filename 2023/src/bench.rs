@@ -0,0 +1,164 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::error::{AocError, Result};
+use crate::params;
+use crate::runner::json_string;
+use crate::solution::Day;
+
+
+/// Summary statistics over repeated runs of the same thing
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Calculate statistics from a set of timings
+    pub fn from_times(times: &[Duration]) -> Stats {
+        let sorted = times.iter().copied().sorted().collect_vec();
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+        let secs = sorted.iter().map(|t| t.as_secs_f64()).collect_vec();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let var = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        Stats{
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(var.sqrt()),
+        }
+    }
+}
+
+
+/// Benchmark results for one stage i.e. parsing or solving one of the parts
+pub struct Bench {
+    pub name: String,
+    pub answer: Option<Answer>,
+    pub stats: Stats,
+}
+
+impl Bench {
+    /// Single JSON object, with times in milliseconds
+    fn to_json(&self) -> String {
+        let answer = match &self.answer {
+            Some(answer) => json_string(&answer.to_string()),
+            None => String::from("null"),
+        };
+        format!(
+            "{{\"name\": {}, \"answer\": {}, \"min_ms\": {}, \"median_ms\": {}, \"mean_ms\": {}, \"stddev_ms\": {}}}",
+            json_string(&self.name), answer,
+            millis(self.stats.min), millis(self.stats.median), millis(self.stats.mean), millis(self.stats.stddev),
+        )
+    }
+}
+
+
+/// Benchmark parsing and the chosen parts of a day, running each `warmup` times before
+/// timing `runs` more. Parsing is timed separately so it doesn't count towards the parts.
+pub fn bench_day(day: &Day, content: &str, parts: &[u8], runs: usize, warmup: usize) -> Result<Vec<Bench>> {
+    let solution = (day.parse)(content)?;
+    params::check(&solution.params()).map_err(AocError::new)?;
+    let mut results = vec![];
+    let (_, times) = repeat(runs, warmup, || (day.parse)(content));
+    results.push(Bench{ name: String::from("parse"), answer: None, stats: Stats::from_times(&times) });
+
+    for &part in parts {
+        let (answer, times) = repeat(runs, warmup, || solution.solve(part));
+        results.push(Bench{
+            name: format!("part{}", part),
+            answer,
+            stats: Stats::from_times(&times),
+        });
+    }
+    Ok(results)
+}
+
+
+/// Print a table of benchmark results
+pub fn print_results(results: &[Bench]) {
+    println!(
+        "{:<6}  {:>20}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "", "ANSWER", "MIN", "MEDIAN", "MEAN", "STDDEV",
+    );
+    for result in results {
+        let answer = result.answer.as_ref().map(|a| a.to_string()).unwrap_or_default();
+        println!(
+            "{:<6}  {:>20}  {:>10}  {:>10}  {:>10}  {:>10}",
+            result.name, answer,
+            format_duration(result.stats.min), format_duration(result.stats.median),
+            format_duration(result.stats.mean), format_duration(result.stats.stddev),
+        );
+    }
+}
+
+
+/// Write benchmark results to a JSON file, so runs can be compared
+pub fn write_json(path: &Path, day: &Day, runs: usize, warmup: usize, results: &[Bench]) -> io::Result<()> {
+    let results = results.iter().map(|r| format!("    {}", r.to_json())).join(",\n");
+    let json = format!(
+        "{{\n  \"year\": {},\n  \"day\": {},\n  \"runs\": {},\n  \"warmup\": {},\n  \"results\": [\n{}\n  ]\n}}\n",
+        day.year, day.day, runs, warmup, results,
+    );
+    fs::write(path, json)
+}
+
+
+/// Run something and time how long it takes
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+
+/// Short human readable duration e.g. 12.3ms or 2m05s
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 60.0 {
+        format!("{}m{:02}s", duration.as_secs() / 60, duration.as_secs() % 60)
+    } else if secs >= 1.0 {
+        format!("{:.2}s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.1}ms", secs * 1e3)
+    } else {
+        format!("{:.1}µs", secs * 1e6)
+    }
+}
+
+
+/// Time something `runs` times, after some untimed warmup runs, keeping the last result
+fn repeat<T>(runs: usize, warmup: usize, mut f: impl FnMut() -> T) -> (Option<T>, Vec<Duration>) {
+    for _ in 0..warmup { f(); }
+    let mut last = None;
+    let times = (0..runs)
+        .map(|_| {
+            let (result, time) = timed(&mut f);
+            last = Some(result);
+            time
+        })
+        .collect();
+    (last, times)
+}
+
+
+/// Milliseconds to the nearest nanosecond
+pub fn millis(duration: Duration) -> String {
+    format!("{:.6}", duration.as_secs_f64() * 1e3)
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+
+/// Shared flag asking some work to stop as soon as it can
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Ask whatever is watching this token to give up
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Has this been cancelled?
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+
+thread_local! {
+    static CURRENT: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+
+/// Set the token watched by solvers running on this thread
+pub fn set_current(token: Option<CancelToken>) {
+    CURRENT.with(|current| *current.borrow_mut() = token);
+}
+
+
+/// Has the solver running on this thread been cancelled? Long loops should check this and
+/// bail out early, as whatever answer they come up with will be thrown away.
+pub fn cancelled() -> bool {
+    CURRENT.with(|current| current.borrow().as_ref().is_some_and(|t| t.is_cancelled()))
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+
+/// Environment variable pointing at the config file, used if `--config` isn't given
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+
+/// Where puzzle inputs are downloaded from, unless the config says otherwise
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+
+/// Settings for talking to the Advent of Code site, read from a file of `key = value` lines e.g.
+///
+/// ```text
+/// session = 53616c7465645f5f...
+/// base_url = http://localhost:8000
+/// ```
+///
+/// Blank lines and lines starting with '#' are ignored
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Value of the `session` cookie from a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config{ session: None, base_url: String::from(DEFAULT_BASE_URL) }
+    }
+}
+
+impl Config {
+    /// Load from an explicit path if given, falling back to the environment variable and then
+    /// `~/.config/aoc/config`. It's fine for the default file not to exist, but not the others.
+    pub fn load(explicit: Option<&str>) -> Result<Config, String> {
+        let explicit = explicit
+            .map(String::from)
+            .or_else(|| env::var(CONFIG_VAR).ok().filter(|v| !v.is_empty()));
+        let path = match &explicit {
+            Some(path) => PathBuf::from(path),
+            None => match env::var("HOME") {
+                Ok(home) => PathBuf::from(home).join(".config").join("aoc").join("config"),
+                Err(_) => return Ok(Config::default()),
+            },
+        };
+        match fs::read_to_string(&path) {
+            Ok(content) => Config::from_string(&content).map_err(|e| format!("{} in {}", e, path.display())),
+            Err(_) if explicit.is_none() => Ok(Config::default()),
+            Err(e) => Err(format!("Can't read config {}: {}", path.display(), e)),
+        }
+    }
+
+    /// Parse `key = value` lines, complaining about anything we don't recognise
+    pub fn from_string(content: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+        for (i, line) in content.split("\n").enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') { continue; }
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("Expected `key = value` on line {}", i + 1));
+            };
+            let value = String::from(value.trim().trim_matches('"'));
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                key => return Err(format!("Unknown setting {:?} on line {}", key, i + 1)),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_settings() {
+        let content = "# for testing\n\nsession = \"53616c74\"\n  base_url=http://localhost:8000  \n";
+        let config = Config::from_string(content).unwrap();
+        assert_eq!(config.session.as_deref(), Some("53616c74"));
+        assert_eq!(config.base_url, "http://localhost:8000");
+    }
+
+    #[test]
+    fn defaults_when_empty() {
+        let config = Config::from_string("").unwrap();
+        assert_eq!(config.session, None);
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+    }
+
+    #[test]
+    fn rejects_unknown_setting() {
+        let error = Config::from_string("session = abc\ncookie = abc").unwrap_err();
+        assert_eq!(error, "Unknown setting \"cookie\" on line 2");
+    }
+
+    #[test]
+    fn rejects_line_without_value() {
+        let error = Config::from_string("# comment\nsession").unwrap_err();
+        assert_eq!(error, "Expected `key = value` on line 2");
+    }
+}
\ No newline at end of file
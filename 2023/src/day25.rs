@@ -1,16 +0,0 @@
-// ...
-
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content);
-}
-
-
-fn part1(_content: &str) {
-    println!("PART 1: {}", -1);
-}
-
-
-fn part2(_content: &str) {
-    println!("PART 2: {}", -1);
-}
\ No newline at end of file
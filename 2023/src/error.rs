@@ -0,0 +1,147 @@
+use std::fmt;
+use std::str::FromStr;
+
+
+/// Problem with a puzzle input, pointing at where it went wrong if we know
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub message: String,
+    /// Line of the input, counting from 0
+    pub line: Option<usize>,
+    /// Column within the line, counting from 0
+    pub column: Option<usize>,
+    /// Offending bit of the input, used to work out the column
+    fragment: Option<String>,
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> AocError {
+        AocError{ message: message.into(), line: None, column: None, fragment: None }
+    }
+
+    /// Error about a bit of the input e.g. `AocError::invalid("number", "12x")`
+    pub fn invalid(what: &str, fragment: &str) -> AocError {
+        let mut error = AocError::new(format!("invalid {} {:?}", what, fragment));
+        error.fragment = Some(String::from(fragment));
+        error
+    }
+
+    /// Point at an exact position in the input
+    pub fn at(mut self, line: usize, column: usize) -> AocError {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    /// Attach the line this error came from, finding the column from the offending fragment.
+    /// Errors which already know where they came from are left alone.
+    pub fn on_line(mut self, index: usize, line: &str) -> AocError {
+        if self.line.is_none() {
+            self.line = Some(index);
+            self.column = self.fragment.as_ref().and_then(|f| line.find(f.as_str()));
+        }
+        self
+    }
+
+    /// Shift the error down, for sections of the input which don't start on the first line
+    pub fn below(mut self, nlines: usize) -> AocError {
+        self.line = self.line.map(|l| l + nlines);
+        self
+    }
+
+    /// Readable diagnostic pointing at the bad line of the input e.g.
+    ///
+    /// ```text
+    /// error in day9: invalid number "x"
+    ///  --> line 3, column 5
+    ///   |
+    /// 3 | 1 2 x
+    ///   |     ^
+    /// ```
+    pub fn render(&self, name: &str, content: &str) -> String {
+        let mut lines = vec![format!("error in {}: {}", name, self.message)];
+        let Some(index) = self.line else {
+            return lines.join("\n");
+        };
+        let number = (index + 1).to_string();
+        let pad = " ".repeat(number.len());
+        match self.column {
+            Some(c) => lines.push(format!("{} --> line {}, column {}", pad, number, c + 1)),
+            None => lines.push(format!("{} --> line {}", pad, number)),
+        }
+        if let Some(text) = content.split("\n").nth(index) {
+            lines.push(format!("{} |", pad));
+            lines.push(format!("{} | {}", number, text));
+            if let Some(c) = self.column {
+                let width = self.fragment.as_ref().map_or(1, |f| f.chars().count().max(1));
+                lines.push(format!("{} | {}{}", pad, " ".repeat(c), "^".repeat(width)));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        match (self.line, self.column) {
+            (Some(l), Some(c)) => write!(f, " at line {}, column {}", l + 1, c + 1),
+            (Some(l), None) => write!(f, " at line {}", l + 1),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+
+/// Parse a number (or anything else), complaining about the text if it's not valid
+pub fn parse_num<T: FromStr>(s: &str) -> Result<T> {
+    s.trim().parse().map_err(|_| AocError::invalid("number", s))
+}
+
+
+/// Split in two around a separator, which has to be there
+pub fn split_pair<'a>(s: &'a str, sep: &str) -> Result<(&'a str, &'a str)> {
+    s.split_once(sep).ok_or_else(|| {
+        let mut error = AocError::new(format!("expected {:?} in {:?}", sep, s));
+        error.fragment = Some(String::from(s));
+        error
+    })
+}
+
+
+/// Parse every line of the input, with errors pointing at the line they came from
+pub fn parse_lines<T>(content: &str, mut f: impl FnMut(&str) -> Result<T>) -> Result<Vec<T>> {
+    content.split("\n")
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| {
+            if line.trim().is_empty() {
+                AocError::new("unexpected blank line").at(i, 0)
+            } else {
+                e.on_line(i, line)
+            }
+        }))
+        .collect()
+}
+
+
+/// Split the input into sections separated by blank lines, along with the line each starts on
+pub fn sections(content: &str) -> Vec<(usize, String)> {
+    let mut sections = vec![];
+    let mut buffer: Vec<&str> = vec![];
+    let mut start = 0;
+    for (i, line) in content.split("\n").enumerate() {
+        if line.trim().is_empty() {
+            if !buffer.is_empty() { sections.push((start, buffer.join("\n"))); }
+            buffer = vec![];
+            start = i + 1;
+        } else {
+            buffer.push(line);
+        }
+    }
+    if !buffer.is_empty() { sections.push((start, buffer.join("\n"))); }
+    sections
+}
\ No newline at end of file
@@ -0,0 +1,125 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::Config;
+
+
+/// Identifies us to the site, as asked for by its automation guidelines
+pub const USER_AGENT: &str = concat!("aoc-2023/", env!("CARGO_PKG_VERSION"));
+
+
+/// Result of asking for a puzzle input
+pub enum Fetched {
+    /// Downloaded and saved to this path
+    Downloaded(PathBuf),
+    /// Already had it at this path, so didn't bother the site
+    Cached(PathBuf),
+}
+
+
+/// Download the input for a day of a year and save it as `dayN.txt` in that year's data dir.
+/// An input we already have is never downloaded again unless `force` is given, although an
+/// empty placeholder (as made by `new`) doesn't count.
+pub fn fetch_input(config: &Config, data_dir: &Path, year: u32, day: u32, force: bool) -> Result<Fetched, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day {}, should be 1 to 25", day));
+    }
+    let path = data_dir.join(format!("day{}.txt", day));
+    let cached = fs::metadata(&path).is_ok_and(|m| m.len() > 0);
+    if cached && !force {
+        return Ok(Fetched::Cached(path));
+    }
+    let Some(session) = &config.session else {
+        return Err(String::from("No session token - add `session = <cookie>` to the config file"));
+    };
+
+    let url = format!("{}/{}/day/{}/input", config.base_url.trim_end_matches('/'), year, day);
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call();
+    let content = match response {
+        Ok(response) => response.into_string().map_err(|e| format!("Can't read response from {}: {}", url, e))?,
+        Err(ureq::Error::Status(status, response)) => {
+            let hint = match status {
+                400 | 401 => " - is the session token still valid?",
+                404 => " - has the puzzle been released yet?",
+                _ => "",
+            };
+            return Err(format!("{} returned {} {}{}", url, status, response.status_text(), hint));
+        },
+        Err(e) => return Err(format!("Can't fetch {}: {}", url, e)),
+    };
+
+    fs::create_dir_all(data_dir).map_err(|e| format!("Can't create {}: {}", data_dir.display(), e))?;
+    fs::write(&path, content).map_err(|e| format!("Can't write {}: {}", path.display(), e))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::{temp_dir, StubServer};
+
+    fn config(base_url: &str) -> Config {
+        Config{ session: Some(String::from("abc123")), base_url: String::from(base_url) }
+    }
+
+    #[test]
+    fn downloads_and_saves_input() {
+        let server = StubServer::serve_once(200, "1 2 3\n4 5 6\n");
+        let dir = temp_dir("fetch-download");
+        let fetched = fetch_input(&config(&server.base_url), &dir, 2023, 5, false).unwrap();
+        let request = server.request();
+
+        assert!(matches!(fetched, Fetched::Downloaded(ref path) if *path == dir.join("day5.txt")));
+        assert_eq!(fs::read_to_string(dir.join("day5.txt")).unwrap(), "1 2 3\n4 5 6\n");
+        assert!(request.starts_with("GET /2023/day/5/input "), "{}", request);
+        assert!(request.to_lowercase().contains("cookie: session=abc123"), "{}", request);
+        assert!(request.contains(USER_AGENT), "{}", request);
+    }
+
+    #[test]
+    fn keeps_cached_input() {
+        let dir = temp_dir("fetch-cached");
+        fs::write(dir.join("day5.txt"), "already here").unwrap();
+        // nothing is listening here, so this would fail if it tried to download
+        let fetched = fetch_input(&config("http://127.0.0.1:1"), &dir, 2023, 5, false).unwrap();
+        assert!(matches!(fetched, Fetched::Cached(_)));
+        assert_eq!(fs::read_to_string(dir.join("day5.txt")).unwrap(), "already here");
+    }
+
+    #[test]
+    fn replaces_empty_placeholder() {
+        let server = StubServer::serve_once(200, "input");
+        let dir = temp_dir("fetch-placeholder");
+        fs::write(dir.join("day5.txt"), "").unwrap();
+        let fetched = fetch_input(&config(&server.base_url), &dir, 2023, 5, false).unwrap();
+        assert!(matches!(fetched, Fetched::Downloaded(_)));
+        assert_eq!(fs::read_to_string(dir.join("day5.txt")).unwrap(), "input");
+    }
+
+    #[test]
+    fn explains_missing_puzzle() {
+        let server = StubServer::serve_once(404, "Not Found");
+        let dir = temp_dir("fetch-missing");
+        let error = fetch_input(&config(&server.base_url), &dir, 2023, 5, false).err().unwrap();
+        assert!(error.contains("404"), "{}", error);
+        assert!(error.contains("released"), "{}", error);
+        assert!(!dir.join("day5.txt").exists());
+    }
+
+    #[test]
+    fn needs_session() {
+        let dir = temp_dir("fetch-session");
+        let config = Config{ session: None, ..config("http://127.0.0.1:1") };
+        let error = fetch_input(&config, &dir, 2023, 5, false).err().unwrap();
+        assert!(error.contains("session"), "{}", error);
+    }
+
+    #[test]
+    fn rejects_invalid_day() {
+        let dir = temp_dir("fetch-day");
+        assert!(fetch_input(&config("http://127.0.0.1:1"), &dir, 2023, 26, false).is_err());
+    }
+}
\ No newline at end of file
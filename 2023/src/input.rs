@@ -0,0 +1,119 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+
+
+/// Environment variable pointing at the data dir, used if `--data-dir` isn't given
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+
+/// Where to look for puzzle inputs, which are kept in a directory per year e.g. `data/2023`.
+/// An explicit `--data-dir` or `AOC_DATA_DIR` is the only place searched, otherwise we try
+/// `data` under the working directory, then under that year's crate (if we're in the directory
+/// above it) and then under this crate.
+#[derive(Debug, Clone)]
+pub struct DataDir {
+    explicit: Option<PathBuf>,
+    cwd: Option<PathBuf>,
+}
+
+impl DataDir {
+    /// Search an explicit data dir if given, falling back to the environment variable and
+    /// then the default locations
+    pub fn new(explicit: Option<&str>) -> DataDir {
+        let explicit = explicit
+            .map(String::from)
+            .or_else(|| env::var(DATA_DIR_VAR).ok().filter(|v| !v.is_empty()))
+            .map(PathBuf::from);
+        DataDir{ explicit, cwd: env::current_dir().ok() }
+    }
+
+    /// Data dirs to search for a year, in order
+    fn dirs(&self, year: u32) -> Vec<PathBuf> {
+        if let Some(dir) = &self.explicit {
+            return vec![dir.clone()];
+        }
+        let mut dirs = vec![];
+        if let Some(cwd) = &self.cwd {
+            dirs.push(cwd.join("data"));
+            dirs.push(cwd.join(year.to_string()).join("data"));
+        }
+        dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("data"));
+        dirs.into_iter().unique().collect()
+    }
+
+    /// Data dir for a year to write files into i.e. the one in the first data dir which exists
+    pub fn path(&self, year: u32) -> PathBuf {
+        let dirs = self.dirs(year);
+        dirs.iter().find(|d| d.is_dir()).unwrap_or(&dirs[0]).join(year.to_string())
+    }
+
+    /// Path of an input file like "day17_test2" for a year, in the first data dir which has it
+    pub fn find(&self, year: u32, input: &str) -> Result<PathBuf, InputError> {
+        let searched = self.dirs(year).iter()
+            .map(|d| d.join(year.to_string()).join(format!("{input}.txt")))
+            .collect::<Vec<_>>();
+        match searched.iter().find(|p| p.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(InputError::NotFound{ input: format!("{}/{}", year, input), searched }),
+        }
+    }
+
+    /// Read an input file like "day17_test2" for a year from the first data dir which has it
+    pub fn read(&self, year: u32, input: &str) -> Result<String, InputError> {
+        read_path(&self.find(year, input)?.to_string_lossy())
+    }
+}
+
+
+/// Read a puzzle input from a path, or from stdin if the path is "-"
+pub fn read_path(path: &str) -> Result<String, InputError> {
+    if path == "-" {
+        let mut content = String::new();
+        io::stdin()
+            .read_to_string(&mut content)
+            .map_err(|e| InputError::Io(PathBuf::from("<stdin>"), e))?;
+        Ok(content)
+    } else {
+        fs::read_to_string(path).map_err(|e| InputError::Io(PathBuf::from(path), e))
+    }
+}
+
+
+/// Tidy up a puzzle input the way every day's parser expects, so downloaded or hand edited
+/// files work as they are: a byte order mark is dropped, CRLF line endings become `\n`, trailing
+/// whitespace is trimmed from each line, and blank lines at the end are removed. Lines keep their
+/// numbers, so errors still point at the right place in the original file.
+pub fn normalise(content: &str) -> String {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let lines = content.split("\n").map(|line| line.trim_end()).collect::<Vec<_>>();
+    let len = lines.iter().rposition(|line| !line.is_empty()).map_or(0, |i| i + 1);
+    lines[..len].join("\n")
+}
+
+
+/// Problem finding or reading a puzzle input
+#[derive(Debug)]
+pub enum InputError {
+    NotFound{ input: String, searched: Vec<PathBuf> },
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound{ input, searched } => {
+                writeln!(f, "Can't find input {}, searched:", input)?;
+                for path in searched {
+                    writeln!(f, "    {}", path.display())?;
+                }
+                write!(f, "Use --data-dir or set {} to look somewhere else", DATA_DIR_VAR)
+            },
+            InputError::Io(path, e) => write!(f, "Can't read {}: {}", path.display(), e),
+        }
+    }
+}
\ No newline at end of file
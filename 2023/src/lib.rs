@@ -0,0 +1,18 @@
+pub mod answer;
+pub mod answer_file;
+pub mod bench;
+pub mod cancel;
+pub mod config;
+pub mod error;
+pub mod fetch;
+pub mod input;
+pub mod params;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+#[cfg(test)]
+mod stub_server;
+pub mod submit;
+pub mod trace;
+pub mod utils;
+pub mod y2023;
\ No newline at end of file
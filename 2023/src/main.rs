@@ -1,111 +1,245 @@
 use std::env;
-use std::fs;
-use std::path;
-
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-pub mod utils;
+use std::fmt::Display;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+use aoc_2023::{bench, fetch, input, params, runner, scaffold, solution, submit, trace};
+use aoc_2023::answer::Answer;
+use aoc_2023::answer_file::{self, AnswerFile};
+use aoc_2023::config::Config;
+use aoc_2023::fetch::Fetched;
+use aoc_2023::input::DataDir;
+use aoc_2023::runner::Format;
+use aoc_2023::submit::{History, Verdict};
+use aoc_2023::trace::Level;
+
+use args::Args;
+
+mod args;
+
+
+/// Every way of running this, shown when it's run without any arguments
+const USAGE: &str = "\
+Usage:
+    [YEAR/]<DAY>[_test[N]]      run a day, or one of its examples
+    all [YEAR]                  run every day
+    bench [YEAR/]<DAY>          time parsing and each part
+    new [YEAR/]<DAY>            create the files for a new day
+    fetch [YEAR/]<DAY>          download an input
+    submit [YEAR/]<DAY> <PART>  submit an answer
+    --check                     re-run every recorded answer
+    --examples [[YEAR/]DAY]     check the examples against their expected answers
+
+The options each takes are described in the README";
 
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-    let data: Data = Data::new(&args);
-    
-    if (data.suffix == "1") | (data.suffix.starts_with("1_")) {
-        day1::run(data.content);
-    } else if (data.suffix == "2") | (data.suffix.starts_with("2_")) {
-        day2::run(data.content);
-    } else if (data.suffix == "3") | (data.suffix.starts_with("3_")) {
-        day3::run(data.content);
-    } else if (data.suffix == "4") | (data.suffix.starts_with("4_")) {
-        day4::run(data.content);
-    } else if (data.suffix == "5") | (data.suffix.starts_with("5_")) {
-        day5::run(data.content);
-    } else if (data.suffix == "6") | (data.suffix.starts_with("6_")) {
-        day6::run(data.content);
-    } else if (data.suffix == "7") | (data.suffix.starts_with("7_")) {
-        day7::run(data.content);
-    } else if (data.suffix == "8") | (data.suffix.starts_with("8_")) {
-        day8::run(data.content);
-    } else if (data.suffix == "9") | (data.suffix.starts_with("9_")) {
-        day9::run(data.content);
-    } else if (data.suffix == "10") | (data.suffix.starts_with("10_")) {
-        day10::run(data.content);
-    } else if (data.suffix == "11") | (data.suffix.starts_with("11_")) {
-        day11::run(data.content);
-    } else if (data.suffix == "12") | (data.suffix.starts_with("12_")) {
-        day12::run(data.content);
-    } else if (data.suffix == "13") | (data.suffix.starts_with("13_")) {
-        day13::run(data.content);
-    } else if (data.suffix == "14") | (data.suffix.starts_with("14_")) {
-        day14::run(data.content);
-    } else if (data.suffix == "15") | (data.suffix.starts_with("15_")) {
-        day15::run(data.content);
-    } else if (data.suffix == "16") | (data.suffix.starts_with("16_")) {
-        day16::run(data.content);
-    } else if (data.suffix == "17") | (data.suffix.starts_with("17_")) {
-        day17::run(data.content);
-    } else if (data.suffix == "18") | (data.suffix.starts_with("18_")) {
-        day18::run(data.content);
-    } else if (data.suffix == "19") | (data.suffix.starts_with("19_")) {
-        day19::run(data.content);
-    } else if (data.suffix == "20") | (data.suffix.starts_with("20_")) {
-        day20::run(data.content);
-    } else if (data.suffix == "21") | (data.suffix.starts_with("21_")) {
-        day21::run(data.content);
-    } else if (data.suffix == "22") | (data.suffix.starts_with("22_")) {
-        day22::run(data.content);
-    } else if (data.suffix == "23") | (data.suffix.starts_with("23_")) {
-        day23::run(data.content);
-    } else if (data.suffix == "24") | (data.suffix.starts_with("24_")) {
-        day24::run(data.content);
-    } else if (data.suffix == "25") | (data.suffix.starts_with("25_")) {
-        day25::run(data.content);
+    let args = Args::parse(env::args().skip(1), &["runs", "warmup", "json", "input", "data-dir", "part", "format", "jobs", "timeout", "config", "trace", "param"]);
+    let data = DataDir::new(args.value("data-dir"));
+    tracing(&args);
+    for spec in args.values("param") {
+        params::set(spec).unwrap_or_else(|e| exit_with(e));
+    }
+
+    if args.has("check") {
+        refuse_params("--check");
+        let passed = runner::check(&data);
+        process::exit(if passed { 0 } else { 1 });
+    }
+    if args.has("examples") {
+        refuse_params("--examples");
+        let (year, day) = match args.positional.first() {
+            Some(arg) => {
+                let (year, day) = year_day(Some(arg), "Usage: --examples [[YEAR/]DAY]");
+                (Some(year), Some(day))
+            },
+            None => (None, None),
+        };
+        let passed = runner::examples(&data, year, day);
+        process::exit(if passed { 0 } else { 1 });
+    }
+    let Some(command) = args.positional.first() else {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+    match command.as_str() {
+        "all" => run_all(&data, &args),
+        "bench" => bench(&data, &args),
+        "new" => new_day(&data, &args),
+        "fetch" => fetch(&data, &args),
+        "submit" => submit(&data, &args),
+        spec => {
+            let Some((year, input)) = solution::parse_input(spec) else {
+                exit_with(format!("Invalid input {}, should be like 17, 17_test2 or 2023/17", spec));
+            };
+            if args.has("validate") {
+                let valid = runner::validate(&data, year, &input, args.value("input")).unwrap_or_else(|e| exit_with(e));
+                process::exit(if valid { 0 } else { 1 });
+            }
+            runner::run(&data, year, &input, &parts(&args), args.value("input"), args.has("record"), format(&args))
+                .unwrap_or_else(|e| exit_with(e));
+        },
+    }
+}
+
+
+/// Parameters are for trying out one day, and would make answers differ from the recorded ones
+fn refuse_params(mode: &str) {
+    if params::any_given() {
+        exit_with(format!("Can't use --param with {}, only when running a single day", mode));
     }
 }
 
-struct Data {
-    suffix: String,
-    content: String,
+
+/// Show diagnostics from every day at a level set by the number of `-v`s, and everything from
+/// the days given by `--trace` (which can be repeated, or a comma separated list)
+fn tracing(args: &Args) {
+    trace::set_level(Level::from_verbosity(args.count("v")));
+    for target in args.values("trace").iter().flat_map(|t| t.split(',')) {
+        trace::add_target(target);
+    }
 }
 
-impl Data {
-    fn new(args: &[String]) -> Data {
-        // Absolutely hideous way of getting the relative path to the data dir!!
-        let this_file = file!();
-        let abspath = fs::canonicalize(&this_file).expect("Oops");
-        let root_dir = abspath.parent().unwrap().parent().unwrap();
-        let data_dir = root_dir.join(path::Path::new("data"));
-
-        let suffix = args[1].trim();
-        let filename = format!("day{suffix}.txt");
-        let filepath = data_dir.join(path::Path::new(&filename));
-
-        let error_msg = format!("Can't find file!");
-        let content = fs::read_to_string(filepath).expect(&error_msg);
-        Data { suffix: suffix.to_string(), content }
+
+/// Year and day from an argument like "17" or "2023/17", defaulting to the latest year.
+/// Exits with the usage if there isn't one.
+fn year_day(arg: Option<&String>, usage: &str) -> (u32, u32) {
+    let year_day = arg
+        .and_then(|arg| solution::parse_input(arg))
+        .and_then(|(year, input)| Some((year, answer_file::day_of(&input)?)));
+    year_day.unwrap_or_else(|| {
+        eprintln!("{}", usage);
+        process::exit(2);
+    })
+}
+
+
+/// Parts chosen with `--part`, defaulting to both
+fn parts(args: &Args) -> Vec<u8> {
+    match args.value("part") {
+        None => vec![1, 2],
+        Some("1") => vec![1],
+        Some("2") => vec![2],
+        Some(part) => exit_with(format!("Invalid part {}, should be 1 or 2", part)),
     }
+}
+
+
+/// Run every day (or every day of a year), on as many threads as we have cores unless given `--jobs`, with an optional
+/// `--timeout` in seconds for each day
+fn run_all(data: &DataDir, args: &Args) {
+    refuse_params("all");
+    let jobs = args.parsed("jobs")
+        .or_else(|| thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1);
+    let timeout = args.parsed::<f64>("timeout").map(|t| {
+        Duration::try_from_secs_f64(t).unwrap_or_else(|_| exit_with(format!("Invalid timeout {}", t)))
+    });
+    let year = args.positional.get(1).map(|y| y.parse().unwrap_or_else(|_| exit_with(format!("Invalid year {}", y))));
+    runner::run_all(data, year, &parts(args), format(args), jobs, timeout);
+}
+
+
+/// Output format chosen with `--format`, defaulting to plain text
+fn format(args: &Args) -> Format {
+    match args.value("format") {
+        None | Some("text") => Format::Text,
+        Some("json") => Format::Json,
+        Some("csv") => Format::Csv,
+        Some(format) => exit_with(format!("Invalid format {}, should be text, json or csv", format)),
+    }
+}
+
+
+/// Create the boilerplate for a new day
+fn new_day(data: &DataDir, args: &Args) {
+    let (year, day) = year_day(args.positional.get(1), "Usage: new [YEAR/]<DAY>");
+    let changed = scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), &data.path(year), year, day)
+        .unwrap_or_else(|e| exit_with(e));
+    for path in changed {
+        println!("Wrote {}", path.display());
+    }
+}
+
+
+/// Download the input for a day, unless we already have it
+fn fetch(data: &DataDir, args: &Args) {
+    let (year, day) = year_day(args.positional.get(1), "Usage: fetch [YEAR/]<DAY> [--force] [--config PATH]");
+    let config = Config::load(args.value("config")).unwrap_or_else(|e| exit_with(e));
+    match fetch::fetch_input(&config, &data.path(year), year, day, args.has("force")) {
+        Ok(Fetched::Downloaded(path)) => println!("Wrote {}", path.display()),
+        Ok(Fetched::Cached(path)) => println!("Already have {}, use --force to download it again", path.display()),
+        Err(e) => exit_with(e),
+    }
+}
+
+
+/// Submit the answer to a part of a day, as long as it's not already known to be wrong
+fn submit(data: &DataDir, args: &Args) {
+    let usage = "Usage: submit [YEAR/]<DAY> <PART> [--config PATH]";
+    let (year, day) = year_day(args.positional.get(1), usage);
+    let Some(part) = args.positional.get(2).and_then(|p| p.parse::<u8>().ok()).filter(|p| [1, 2].contains(p)) else {
+        eprintln!("{}", usage);
+        process::exit(2);
+    };
+    if params::any_given() {
+        exit_with("Can't submit answers found with a --param, as they aren't for the real puzzle");
+    }
+    let config = Config::load(args.value("config")).unwrap_or_else(|e| exit_with(e));
+    let input = format!("day{}", day);
+    let answer = runner::solve(data, year, &input, part).unwrap_or_else(|e| exit_with(e));
+    if answer == Answer::Unsolved {
+        exit_with(format!("{} day {} part {} isn't solved yet", year, day, part));
+    }
+
+    println!("Submitting {} for {} day {} part {}", answer, year, day, part);
+    let mut history = History::load(&data.path(year).join(submit::HISTORY));
+    let (verdict, wait) = submit::submit_answer(&config, &mut history, year, day, part, &answer.to_string())
+        .unwrap_or_else(|e| exit_with(e));
+    println!("Answer was {}", verdict);
+    if let Some(wait) = wait {
+        println!("{}", wait);
+    }
+    if verdict != Verdict::Correct {
+        process::exit(1);
+    }
+    let mut answers = AnswerFile::load(&data.path(year).join(runner::ANSWERS));
+    answers.set(&input, part, &answer);
+    answers.save().unwrap_or_else(|e| exit_with(format!("Can't write answers file: {}", e)));
+}
+
+
+/// Benchmark a day, or just one part of it
+fn bench(data: &DataDir, args: &Args) {
+    let usage = "Usage: bench [YEAR/]<DAY> [--part N] [--runs N] [--warmup N] [--json PATH] [--input PATH]";
+    let (year, day) = year_day(args.positional.get(1), usage);
+    let Some(day) = solution::lookup(year, day) else {
+        exit_with(format!("No solution registered for {}/day{}", year, day));
+    };
+    let parts = parts(args);
+    let runs = args.parsed("runs").unwrap_or(10).max(1);
+    let warmup = args.parsed("warmup").unwrap_or(1);
+
+    let content = match args.value("input") {
+        Some(path) => input::read_path(path),
+        None => data.read(year, &day.input()),
+    };
+    let content = content.unwrap_or_else(|e| exit_with(e));
+    println!("Benchmarking {}/day{} with {} runs after {} warmup\n", year, day.day, runs, warmup);
+    let results = bench::bench_day(&day, &content, &parts, runs, warmup)
+        .unwrap_or_else(|e| exit_with(e.render(&day.input(), &content)));
+    bench::print_results(&results);
+    if let Some(path) = args.value("json") {
+        bench::write_json(Path::new(path), &day, runs, warmup, &results)
+            .unwrap_or_else(|e| exit_with(format!("Can't write {}: {}", path, e)));
+    }
+}
+
+
+/// Print an error and give up
+fn exit_with(message: impl Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
 }
\ No newline at end of file
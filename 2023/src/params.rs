@@ -0,0 +1,99 @@
+use std::fmt;
+use std::sync::Mutex;
+
+use itertools::Itertools;
+
+
+/// A puzzle constant which can be changed from the command line with `--param name=value`,
+/// e.g. to use the smaller numbers from the examples
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: usize,
+    /// Smallest value the day can cope with
+    pub min: usize,
+    pub about: &'static str,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, default: usize, about: &'static str) -> Param {
+        Param{ name, default, min: 0, about }
+    }
+
+    /// Same parameter, refusing values below `min`
+    pub const fn at_least(self, min: usize) -> Param {
+        Param{ min, ..self }
+    }
+
+    /// Value given on the command line, if any
+    pub fn given(&self) -> Option<usize> {
+        OVERRIDES.lock().unwrap()
+            .iter()
+            .rev()
+            .find(|(name, _)| name == self.name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Value to use, which is the default unless given on the command line
+    pub fn value(&self) -> usize {
+        self.given().unwrap_or(self.default)
+    }
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={} ({}", self.name, self.default, self.about)?;
+        if self.min > 0 {
+            write!(f, ", at least {}", self.min)?;
+        }
+        write!(f, ")")
+    }
+}
+
+
+/// Values given on the command line, which apply to any day with a parameter of that name
+static OVERRIDES: Mutex<Vec<(String, usize)>> = Mutex::new(vec![]);
+
+
+/// Override a parameter given like "steps=500"
+pub fn set(spec: &str) -> Result<(), String> {
+    let Some((name, value)) = spec.split_once('=') else {
+        return Err(format!("Invalid parameter {}, should be like steps=500", spec));
+    };
+    let value = value.trim().replace('_', "").parse()
+        .map_err(|_| format!("Invalid value for parameter {}: {}", name.trim(), value.trim()))?;
+    OVERRIDES.lock().unwrap().push((String::from(name.trim()), value));
+    Ok(())
+}
+
+
+/// Have any parameters been overridden?
+pub fn any_given() -> bool {
+    !OVERRIDES.lock().unwrap().is_empty()
+}
+
+
+/// Make sure every overridden parameter is one a day has, so a typo doesn't go unnoticed, and
+/// that its value is one the day can cope with
+pub fn check(declared: &[Param]) -> Result<(), String> {
+    let overrides = OVERRIDES.lock().unwrap();
+    for (name, value) in overrides.iter() {
+        if let Some(param) = declared.iter().find(|p| (p.name == *name) && (*value < p.min)) {
+            return Err(format!("Parameter {} must be at least {}, not {}", name, param.min, value));
+        }
+    }
+    let unknown = overrides.iter()
+        .map(|(name, _)| name)
+        .filter(|name| !declared.iter().any(|p| p.name == *name))
+        .unique()
+        .collect_vec();
+    if unknown.is_empty() {
+        return Ok(());
+    }
+    let expected = if declared.is_empty() {
+        String::from("there aren't any")
+    } else {
+        format!("expected one of:\n{}", declared.iter().map(|p| format!("    {}", p)).join("\n"))
+    };
+    Err(format!("Unknown parameter {}, {}", unknown.iter().join(", "), expected))
+}
\ No newline at end of file
@@ -0,0 +1,554 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::answer_file::{self, AnswerFile};
+use crate::bench::{format_duration, millis, timed};
+use crate::input::{self, DataDir};
+use crate::params;
+use crate::cancel::{self, CancelToken};
+use crate::solution::{self, Day, Solution};
+
+
+/// Known answers for the real inputs, in the data dir for each year
+pub const ANSWERS: &str = "answers.txt";
+
+
+/// How to print the results of a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// "PART 1:" lines for a single day, or a table for all days
+    Text,
+    /// JSON array with one object per day and part
+    Json,
+    /// CSV with a header row, then one row per day and part
+    Csv,
+}
+
+
+/// Answer and wall time for one part of a day, as printed by the machine readable formats
+pub struct RunRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part: u8,
+    /// Path of the input file, or just its name if it couldn't be found
+    pub input: String,
+    /// Missing if the input couldn't be read or parsed, or solving it panicked
+    pub answer: Option<Answer>,
+    pub time: Duration,
+}
+
+impl RunRecord {
+    /// Did this part come up with an answer?
+    pub fn success(&self) -> bool {
+        matches!(&self.answer, Some(answer) if *answer != Answer::Unsolved)
+    }
+
+    /// Answer as text, empty if there isn't one
+    fn answer_text(&self) -> String {
+        match &self.answer {
+            Some(answer) if self.success() => answer.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// Single JSON object, with the time in milliseconds
+    fn to_json(&self) -> String {
+        let answer = if self.success() { json_string(&self.answer_text()) } else { String::from("null") };
+        format!(
+            "{{\"year\": {}, \"day\": {}, \"part\": {}, \"input\": {}, \"answer\": {}, \"time_ms\": {}, \"success\": {}}}",
+            self.year, self.day, self.part, json_string(&self.input), answer, millis(self.time), self.success(),
+        )
+    }
+
+    /// Single CSV row, in the same order as the header
+    fn to_csv(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.year, self.day, self.part, csv_field(&self.input), csv_field(&self.answer_text()), millis(self.time), self.success(),
+        )
+    }
+}
+
+
+/// Run the chosen parts on an input file for a year like "day17_test2", optionally recording the
+/// answers. An explicit path (or "-" for stdin) can be given to read the puzzle from instead.
+/// Returns a message describing the problem if anything goes wrong.
+pub fn run(
+    data: &DataDir, year: u32, input: &str, parts: &[u8], path: Option<&str>, record: bool, format: Format,
+) -> Result<(), String> {
+    if record && path.is_some() {
+        return Err(String::from("Can't record answers for an explicit --input"));
+    }
+    if record && params::any_given() {
+        return Err(String::from("Can't record answers found with a --param"));
+    }
+    let (path, day, solution) = parse(data, year, input, path)?;
+    let mut records = vec![];
+    for &part in parts {
+        let (answer, time) = timed(|| solution.solve(part));
+        if format == Format::Text {
+            println!("PART {}: {}", part, answer);
+        }
+        records.push(RunRecord{ year, day: day.day, part, input: path.clone(), answer: Some(answer), time });
+    }
+    print_records(&records, format);
+    if record {
+        let mut file = if answer_file::is_example(input) {
+            AnswerFile::load_expected(&data.path(year), input)
+        } else {
+            AnswerFile::load(&data.path(year).join(ANSWERS))
+        };
+        for record in records.iter() {
+            match &record.answer {
+                Some(answer) if record.success() => file.set(input, record.part, answer),
+                _ => (),
+            }
+        }
+        file.save().map_err(|e| format!("Can't write answers file: {}", e))?;
+    }
+    Ok(())
+}
+
+
+/// Parse an input file (or an explicit path) and report whether it holds to each assumption
+/// its solution relies on, returning whether they all did
+pub fn validate(data: &DataDir, year: u32, input: &str, path: Option<&str>) -> Result<bool, String> {
+    let (_, day, solution) = parse(data, year, input, path)?;
+    let checks = solution.checks();
+    if checks.is_empty() {
+        println!("No assumptions to check for {}/day{}", year, day.day);
+    }
+    for check in checks.iter() {
+        match &check.problem {
+            None => println!("PASS  {}", check.assumption),
+            Some(problem) => println!("FAIL  {}: {}", check.assumption, problem),
+        }
+    }
+    let nfail = checks.iter().filter(|c| !c.holds()).count();
+    println!("\n{} held, {} broken", checks.len() - nfail, nfail);
+    Ok(nfail == 0)
+}
+
+
+/// Read and parse an input file, or an explicit path to read it from instead, making sure any
+/// parameters given are ones the day has. Returns the path read along with the day and its parsed
+/// solution.
+fn parse(data: &DataDir, year: u32, input: &str, path: Option<&str>) -> Result<(String, Day, Box<dyn Solution>), String> {
+    let path = match path {
+        Some(path) => String::from(path),
+        None => data.find(year, input).map_err(|e| e.to_string())?.display().to_string(),
+    };
+    let content = input::read_path(&path).map_err(|e| e.to_string())?;
+    let day = lookup(year, input)?;
+    let solution = (day.parse)(&content).map_err(|e| e.render(input, &content))?;
+    params::check(&solution.params())?;
+    Ok((path, day, solution))
+}
+
+
+/// Solve one part of an input file for a year like "day17"
+pub fn solve(data: &DataDir, year: u32, input: &str, part: u8) -> Result<Answer, String> {
+    let content = data.read(year, input).map_err(|e| e.to_string())?;
+    let day = lookup(year, input)?;
+    let solution = (day.parse)(&content).map_err(|e| e.render(input, &content))?;
+    Ok(solution.solve(part))
+}
+
+
+/// Registered solution for an input file of a year
+fn lookup(year: u32, input: &str) -> Result<Day, String> {
+    answer_file::day_of(input)
+        .and_then(|day| solution::lookup(year, day))
+        .ok_or_else(|| format!("No solution registered for {}/{}", year, input))
+}
+
+
+/// Run every registered day (of every year, or just one) on its real input, printing a table
+/// of answers and timings (or a record per day and part). Days are run on a pool of `jobs`
+/// threads, and any day still going after `timeout` is cancelled and reported as TIMEOUT.
+/// A day which panics doesn't stop the rest from running.
+pub fn run_all(
+    data: &DataDir, year: Option<u32>, parts: &[u8], format: Format, jobs: usize, timeout: Option<Duration>,
+) {
+    let text = format == Format::Text;
+    if text {
+        println!(
+            "{:<10}  {:>20}  {:>20}  {:>10}  {:>10}  {:>10}",
+            "DAY", "PART 1", "PART 2", "PARSE", "PART 1", "PART 2",
+        );
+    }
+    let days = solution::all_days().filter(|d| year.is_none_or(|y| d.year == y)).collect_vec();
+    let ndays = days.len();
+    let pool = Arc::new(Pool{
+        data: data.clone(),
+        days,
+        parts: parts.to_vec(),
+        runs: (0..ndays).map(|_| Mutex::new(DayRun::default())).collect(),
+        tokens: (0..ndays).map(|_| CancelToken::new()).collect(),
+        next: AtomicUsize::new(0),
+    });
+    let (sender, receiver) = mpsc::channel();
+    for _ in 0..jobs.max(1).min(ndays) {
+        spawn_worker(&pool, &sender);
+    }
+
+    let mut timed_out = vec![false; ndays];
+    let mut totals = [Duration::ZERO; 3];
+    let mut records = vec![];
+    let mut nprinted = 0;
+    while nprinted < ndays {
+        // print rows in order as soon as they're ready
+        while nprinted < ndays {
+            let run = pool.runs[nprinted].lock().unwrap().clone();
+            if !run.finished && !timed_out[nprinted] { break; }
+            let day = pool.days[nprinted];
+            let times = report_day(&day, &run, parts, timed_out[nprinted], text, &mut records);
+            for (total, time) in totals.iter_mut().zip(times) {
+                *total += time;
+            }
+            nprinted += 1;
+        }
+        if nprinted == ndays { break; }
+
+        // wait for a day to finish, or the next one to run out of time
+        let deadline = timeout.and_then(|timeout| (nprinted..ndays)
+            .filter(|&i| !timed_out[i])
+            .filter_map(|i| {
+                let run = pool.runs[i].lock().unwrap();
+                if run.finished { None } else { run.started.map(|s| s + timeout) }
+            })
+            .min());
+        match deadline {
+            Some(deadline) => { let _ = receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())); },
+            None => { let _ = receiver.recv(); },
+        }
+
+        // anything over time is cancelled, and its thread replaced in case it doesn't notice
+        let Some(timeout) = timeout else { continue };
+        for (i, timed_out) in timed_out.iter_mut().enumerate().skip(nprinted) {
+            let run = pool.runs[i].lock().unwrap();
+            let expired = run.started.is_some_and(|s| s.elapsed() >= timeout);
+            if !run.finished && !*timed_out && expired {
+                *timed_out = true;
+                pool.tokens[i].cancel();
+                spawn_worker(&pool, &sender);
+            }
+        }
+    }
+    if text {
+        println!(
+            "{:<10}  {:>20}  {:>20}  {:>10}  {:>10}  {:>10}",
+            "TOTAL", "", "",
+            format_duration(totals[0]), format_time(parts, 1, totals[1]), format_time(parts, 2, totals[2]),
+        );
+        println!("\nTotal time: {}", format_duration(totals.iter().sum()));
+    }
+    print_records(&records, format);
+}
+
+
+/// Everything the threads running days share
+struct Pool {
+    data: DataDir,
+    /// Days to run, in order
+    days: Vec<Day>,
+    parts: Vec<u8>,
+    /// Progress of each day, in the same order
+    runs: Vec<Mutex<DayRun>>,
+    tokens: Vec<CancelToken>,
+    /// Index of the next day to be picked up
+    next: AtomicUsize,
+}
+
+
+/// Progress running the chosen parts of a day on its real input, filled in as it goes
+#[derive(Clone, Default)]
+struct DayRun {
+    started: Option<Instant>,
+    finished: bool,
+    /// Path of the input file, once it's been found
+    path: Option<String>,
+    /// What went wrong, if the parts couldn't be run
+    error: Option<&'static str>,
+    /// Time taken to parse the input, once it's been parsed
+    parse_time: Option<Duration>,
+    /// Answer (missing if it panicked) and time taken by each part run so far
+    answers: Vec<(u8, Option<Answer>, Duration)>,
+}
+
+
+/// Start a thread which keeps picking up days to run until there are none left
+fn spawn_worker(pool: &Arc<Pool>, sender: &Sender<()>) {
+    let pool = Arc::clone(pool);
+    let sender = sender.clone();
+    thread::spawn(move || loop {
+        let i = pool.next.fetch_add(1, Ordering::Relaxed);
+        let Some(day) = pool.days.get(i) else { return };
+        cancel::set_current(Some(pool.tokens[i].clone()));
+        pool.runs[i].lock().unwrap().started = Some(Instant::now());
+        let _ = sender.send(());
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| run_day(&pool, i, day)));
+        let mut run = pool.runs[i].lock().unwrap();
+        if result.is_err() {
+            run.error.get_or_insert("panicked");
+        }
+        run.finished = true;
+        drop(run);
+        let _ = sender.send(());
+        // a cancelled day has already had its thread replaced
+        if pool.tokens[i].is_cancelled() { return; }
+    });
+}
+
+
+/// Run the chosen parts of a day, saving progress as we go
+fn run_day(pool: &Pool, i: usize, day: &Day) {
+    let input = day.input();
+    let found = pool.data.find(day.year, &input).and_then(|path| {
+        let path = path.display().to_string();
+        let content = input::read_path(&path)?;
+        Ok((path, content))
+    });
+    let Ok((path, content)) = found else {
+        pool.runs[i].lock().unwrap().error = Some("no input");
+        return;
+    };
+    pool.runs[i].lock().unwrap().path = Some(path);
+    let (solution, parse_time) = timed(|| (day.parse)(&content));
+    let solution = match solution {
+        Ok(solution) => solution,
+        Err(e) => {
+            eprintln!("{}", e.render(&input, &content));
+            pool.runs[i].lock().unwrap().error = Some("parse error");
+            return;
+        },
+    };
+    pool.runs[i].lock().unwrap().parse_time = Some(parse_time);
+    for &part in pool.parts.iter() {
+        let (answer, time) = timed(|| panic::catch_unwind(AssertUnwindSafe(|| solution.solve(part))));
+        pool.runs[i].lock().unwrap().answers.push((part, answer.ok(), time));
+    }
+}
+
+
+/// Print a table row for a day (if printing text) and add its records, returning the parse
+/// and part times. Parts which didn't finish in time are shown as TIMEOUT.
+fn report_day(
+    day: &Day, run: &DayRun, parts: &[u8], timed_out: bool, text: bool, records: &mut Vec<RunRecord>,
+) -> [Duration; 3] {
+    let (year, day) = (day.year, day.day);
+    let input = format!("{}/day{}", year, day);
+    let path = run.path.clone().unwrap_or_else(|| input.clone());
+    let error = if timed_out { None } else { run.error };
+    if let Some(error) = error {
+        if text { println!("{:<10}  {:>20}", input, error); }
+        for &part in parts {
+            records.push(RunRecord{ year, day, part, input: path.clone(), answer: None, time: Duration::ZERO });
+        }
+        return [Duration::ZERO; 3];
+    }
+
+    let mut answers = [String::from("-"), String::from("-")];
+    let parse_time = run.parse_time.unwrap_or_default();
+    let mut times = [parse_time, Duration::ZERO, Duration::ZERO];
+    let mut time_text = [run.parse_time.map_or(String::from("-"), format_duration), String::from("-"), String::from("-")];
+    for &part in parts {
+        let (answer, time) = match run.answers.iter().find(|(p, _, _)| *p == part) {
+            Some((_, answer, time)) => (answer.clone(), *time),
+            None => (None, Duration::ZERO),
+        };
+        answers[part as usize - 1] = match &answer {
+            Some(answer) => answer.to_string(),
+            None if timed_out => String::from("TIMEOUT"),
+            None => String::from("panicked"),
+        };
+        times[part as usize] = time;
+        time_text[part as usize] = if answer.is_some() { format_duration(time) } else { String::from("-") };
+        records.push(RunRecord{ year, day, part, input: path.clone(), answer, time });
+    }
+    if text {
+        println!(
+            "{:<10}  {:>20}  {:>20}  {:>10}  {:>10}  {:>10}",
+            input, answers[0], answers[1], time_text[0], time_text[1], time_text[2],
+        );
+    }
+    times
+}
+
+
+/// Print records in one of the machine readable formats, plain text is printed as we go instead
+fn print_records(records: &[RunRecord], format: Format) {
+    match format {
+        Format::Text => (),
+        Format::Json => {
+            let lines = records.iter().map(|r| format!("  {}", r.to_json())).join(",\n");
+            println!("[\n{}\n]", lines);
+        },
+        Format::Csv => {
+            println!("year,day,part,input,answer,time_ms,success");
+            for record in records {
+                println!("{}", record.to_csv());
+            }
+        },
+    }
+}
+
+
+/// Quoted JSON string, escaping anything which needs it
+pub fn json_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+
+/// CSV field, quoted if it contains anything which would confuse a CSV reader
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        String::from(s)
+    }
+}
+
+
+/// Time taken by a part, or a dash if it wasn't run
+fn format_time(parts: &[u8], part: u8, time: Duration) -> String {
+    if parts.contains(&part) { format_duration(time) } else { String::from("-") }
+}
+
+
+/// Year and input file, along with the parts to run on it and their expected answers if known
+type Expected = (u32, String, Vec<(u8, Option<String>)>);
+
+
+/// Outcome of re-running a part of an input file
+enum Status {
+    Pass,
+    Changed(Answer),
+    Fail(String),
+    Unchecked(String),
+    Skipped(String),
+}
+
+
+/// Re-run every recorded answer of every year and report on any which no longer match
+pub fn check(data: &DataDir) -> bool {
+    let mut inputs = vec![];
+    for year in solution::years() {
+        let file = AnswerFile::load(&data.path(year).join(ANSWERS));
+        inputs.extend(file.records()
+            .iter()
+            .group_by(|r| r.input.clone())
+            .into_iter()
+            .map(|(input, records)| (year, input, records.map(|r| (r.part, Some(r.answer.clone()))).collect_vec())));
+    }
+    report(data, &inputs)
+}
+
+
+/// Run both parts on every example input for a day of a year (or all days), comparing against
+/// the expected answers stored alongside them
+pub fn examples(data: &DataDir, year: Option<u32>, day: Option<u32>) -> bool {
+    let inputs = solution::all_days()
+        .filter(|d| year.is_none_or(|y| d.year == y) && day.is_none_or(|n| d.day == n))
+        .flat_map(|d| answer_file::examples(&data.path(d.year), d.day).into_iter().map(move |input| (d.year, input)))
+        .map(|(year, input)| {
+            let expected = AnswerFile::load_expected(&data.path(year), &input);
+            let parts = (1..=2)
+                .map(|part| (part, expected.get(&input, part).map(String::from)))
+                .collect_vec();
+            (year, input, parts)
+        })
+        .collect_vec();
+    report(data, &inputs)
+}
+
+
+/// Run the given parts of each input file and print how they compare with the expected
+/// answers, returning whether they all matched. Parts with no expected answer are just shown.
+fn report(data: &DataDir, inputs: &[Expected]) -> bool {
+    let (mut npass, mut nchanged, mut nfail, mut nskipped) = (0, 0, 0, 0);
+    for (year, input, parts) in inputs {
+        let statuses = check_input(data, *year, input, parts);
+        for ((part, expected), status) in parts.iter().zip(statuses) {
+            let label = format!("{:<19} part {}", format!("{}/{}", year, input), part);
+            match status {
+                Status::Pass => {
+                    npass += 1;
+                    println!("{}  PASS", label);
+                },
+                Status::Changed(answer) => {
+                    nchanged += 1;
+                    println!("{}  CHANGED  expected {}, got {}", label, expected.as_deref().unwrap_or(""), answer);
+                },
+                Status::Fail(reason) => {
+                    nfail += 1;
+                    println!("{}  FAIL     {}", label, reason);
+                },
+                Status::Unchecked(result) => {
+                    println!("{}  -        {}, no expected answer", label, result);
+                },
+                Status::Skipped(reason) => {
+                    nskipped += 1;
+                    println!("{}  SKIP     {}", label, reason);
+                },
+            }
+        }
+    }
+    println!("\n{} passed, {} changed, {} failed, {} skipped", npass, nchanged, nfail, nskipped);
+    (nchanged == 0) && (nfail == 0)
+}
+
+
+/// Run the given parts of a single input file
+fn check_input(data: &DataDir, year: u32, input: &str, parts: &[(u8, Option<String>)]) -> Vec<Status> {
+    let fail = |reason: &str| parts.iter().map(|_| Status::Fail(String::from(reason))).collect();
+    let Ok(day) = lookup(year, input) else {
+        return fail("no solution registered");
+    };
+    let Ok(content) = data.read(year, input) else {
+        return fail("input file not found");
+    };
+    let solution = match panic::catch_unwind(|| (day.parse)(&content)) {
+        Ok(Ok(solution)) => solution,
+        Ok(Err(e)) => return fail(&format!("parse error: {}", e)),
+        Err(_) => return fail("panicked while parsing"),
+    };
+    parts.iter()
+        .map(|(part, expected)| {
+            if let Some(reason) = expected.as_deref().and_then(answer_file::skip_reason) {
+                return Status::Skipped(String::from(reason));
+            }
+            let answer = panic::catch_unwind(AssertUnwindSafe(|| solution.solve(*part)));
+            let Some(expected) = expected else {
+                return match answer {
+                    Ok(answer) => Status::Unchecked(format!("got {}", answer)),
+                    Err(_) => Status::Unchecked(String::from("panicked")),
+                };
+            };
+            match answer {
+                Ok(answer) if answer.to_string() == *expected => Status::Pass,
+                Ok(Answer::Unsolved) => Status::Fail(String::from("no longer solved")),
+                Ok(answer) => Status::Changed(answer),
+                Err(_) => Status::Fail(String::from("panicked")),
+            }
+        })
+        .collect()
+}
\ No newline at end of file
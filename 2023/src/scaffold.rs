@@ -0,0 +1,197 @@
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::solution;
+
+
+/// Starting point for a new day, matching the shape of the others
+const TEMPLATE: &str = "// ...
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::Solution;
+
+pub struct Day{N};
+
+impl Solution for Day{N} {
+    fn parse(_content: &str) -> Result<Day{N}> {
+        Ok(Day{N})
+    }
+
+    fn part1(&self) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(&self) -> Answer {
+        Answer::Unsolved
+    }
+}";
+
+
+/// Stub for the expected answers of the example input
+const EXPECTED: &str = "# part answer e.g. \"1 142\", for checking with --examples\n";
+
+
+/// Registry for a new year, which days are then added to
+const YEAR_TEMPLATE: &str = "
+use crate::solution::{boxed, Day};
+
+
+/// Every registered day of the {Y} event, in order
+pub const DAYS: [Day; 0] = [
+];";
+
+
+/// Create `src/yYEAR/dayN.rs` from the template and register it in the `DAYS` list of that
+/// year's module, adding the module if this is the first day of a new year. Empty input files and
+/// an expected answers stub are added to the year's data dir, although existing data files are
+/// left alone. Returns every file created or changed.
+pub fn new_day(crate_dir: &Path, data_dir: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Invalid day {}, should be 1 to 25", day));
+    }
+    if !(2015..=2100).contains(&year) {
+        return Err(format!("Invalid year {}", year));
+    }
+    let src = crate_dir.join("src");
+    let year_dir = src.join(format!("y{}", year));
+    let module = year_dir.join(format!("day{}.rs", day));
+    if solution::lookup(year, day).is_some() || module.exists() {
+        return Err(format!("Day {} already exists in {}", day, module.display()));
+    }
+
+    // work out all the changes before writing anything
+    let mut files = vec![(module.clone(), TEMPLATE.replace("{N}", &day.to_string()))];
+    let registry = year_dir.join("mod.rs");
+    let registry_content = if registry.exists() {
+        read(&registry)?
+    } else {
+        let lib = src.join("lib.rs");
+        let years = src.join("solution.rs");
+        let lib_content = register_year_module(&read(&lib)?, year)
+            .ok_or_else(|| format!("Can't find where to add y{} in {}", year, lib.display()))?;
+        let years_content = register_year(&read(&years)?, year)
+            .ok_or_else(|| format!("Can't find where to add y{} in {}", year, years.display()))?;
+        files.push((lib, lib_content));
+        files.push((years, years_content));
+        YEAR_TEMPLATE.replace("{Y}", &year.to_string())
+    };
+    let registry_content = register_module(&registry_content, day)
+        .and_then(|content| register_day(&content, year, day))
+        .ok_or_else(|| format!("Can't find where to add day{} in {}", day, registry.display()))?;
+    files.push((registry, registry_content));
+
+    fs::create_dir_all(&year_dir).map_err(|e| format!("Can't create {}: {}", year_dir.display(), e))?;
+    let mut changed = vec![];
+    for (path, content) in files {
+        write(&path, &content)?;
+        changed.push(path);
+    }
+
+    fs::create_dir_all(data_dir).map_err(|e| format!("Can't create {}: {}", data_dir.display(), e))?;
+    let data_files = [
+        (format!("day{}.txt", day), ""),
+        (format!("day{}_test.txt", day), ""),
+        (format!("day{}_test.expected", day), EXPECTED),
+    ];
+    for (name, content) in data_files {
+        let path = data_dir.join(name);
+        if !path.exists() {
+            write(&path, content)?;
+            changed.push(path);
+        }
+    }
+    Ok(changed)
+}
+
+
+/// Add `pub mod dayN;` to the list of day modules, keeping them in order
+fn register_module(registry: &str, day: u32) -> Option<String> {
+    let mut lines = registry.split("\n").map(String::from).collect::<Vec<_>>();
+    let index = insert_index(&lines, 0..lines.len(), day, |line| {
+        line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok()
+    });
+    lines.insert(index.unwrap_or(0), format!("pub mod day{};", day));
+    Some(lines.join("\n"))
+}
+
+
+/// Add `DayN` to `DAYS`, keeping the days in order
+fn register_day(registry: &str, year: u32, day: u32) -> Option<String> {
+    let mut lines = registry.split("\n").map(String::from).collect::<Vec<_>>();
+    let (start, end) = bump_list(&mut lines, "pub const DAYS: [Day; ")?;
+    let index = insert_index(&lines, start + 1..end, day, |line| {
+        line.trim().strip_prefix("Day::new(")?.split(", ").nth(1)?.parse().ok()
+    });
+    lines.insert(index.unwrap_or(start + 1), format!("    Day::new({}, {}, boxed::<day{}::Day{}>),", year, day, day, day));
+    Some(lines.join("\n"))
+}
+
+
+/// Add `pub mod yYEAR;` to the library, after any earlier years
+fn register_year_module(lib: &str, year: u32) -> Option<String> {
+    let mut lines = lib.split("\n").map(String::from).collect::<Vec<_>>();
+    let index = insert_index(&lines, 0..lines.len(), year, |line| {
+        line.strip_prefix("pub mod y")?.strip_suffix(';')?.parse().ok()
+    });
+    lines.insert(index.unwrap_or(lines.len()), format!("pub mod y{};", year));
+    Some(lines.join("\n"))
+}
+
+
+/// Import the year's module and add its days to `YEARS`, keeping the years in order
+fn register_year(registry: &str, year: u32) -> Option<String> {
+    let mut lines = registry.split("\n").map(String::from).collect::<Vec<_>>();
+    let index = insert_index(&lines, 0..lines.len(), year, |line| {
+        line.strip_prefix("use crate::y")?.strip_suffix(';')?.parse().ok()
+    })?;
+    lines.insert(index, format!("use crate::y{};", year));
+
+    let (start, end) = bump_list(&mut lines, "pub const YEARS: [&[Day]; ")?;
+    let index = insert_index(&lines, start + 1..end, year, |line| {
+        line.trim().strip_prefix("&y")?.strip_suffix("::DAYS,")?.parse().ok()
+    });
+    lines.insert(index.unwrap_or(start + 1), format!("    &y{}::DAYS,", year));
+    Some(lines.join("\n"))
+}
+
+
+/// Line to insert a new entry at so a range of lines stays in order, i.e. after the last line with
+/// an earlier key. Lines without a key are skipped, and if there aren't any earlier entries we go
+/// before the first one.
+fn insert_index(
+    lines: &[String], range: Range<usize>, key: u32, key_of: impl Fn(&str) -> Option<u32>,
+) -> Option<usize> {
+    let keys = range
+        .filter_map(|i| Some((i, key_of(&lines[i])?)))
+        .collect::<Vec<_>>();
+    let earlier = keys.iter().filter(|(_, k)| *k < key).map(|(i, _)| i + 1).next_back();
+    earlier.or_else(|| keys.first().map(|(i, _)| *i))
+}
+
+
+/// Find a constant array starting with `prefix` followed by its size, and bump the size by one.
+/// Returns the lines the array starts and ends on.
+fn bump_list(lines: &mut [String], prefix: &str) -> Option<(usize, usize)> {
+    let start = lines.iter().position(|l| l.starts_with(prefix))?;
+    let size = lines[start]
+        .strip_prefix(prefix)?
+        .split(']')
+        .next()?
+        .parse::<usize>()
+        .ok()?;
+    lines[start] = lines[start].replacen(&format!("; {}]", size), &format!("; {}]", size + 1), 1);
+    let end = start + lines[start..].iter().position(|l| l.trim() == "];")?;
+    Some((start, end))
+}
+
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("Can't read {}: {}", path.display(), e))
+}
+
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| format!("Can't write {}: {}", path.display(), e))
+}
\ No newline at end of file
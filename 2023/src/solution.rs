@@ -0,0 +1,133 @@
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::input;
+use crate::params::Param;
+use crate::y2023;
+
+
+/// Common interface for a day's puzzle - parse the input once, then solve each part
+pub trait Solution {
+    /// Parse the puzzle input, failing with an error pointing at anything unexpected. Going
+    /// through `boxed` (as every registered day does) passes it through `input::normalise` first,
+    /// so there are no `\r`s, trailing spaces or trailing blank lines to worry about.
+    fn parse(content: &str) -> Result<Self> where Self: Sized;
+
+    /// Solve part 1
+    fn part1(&self) -> Answer;
+
+    /// Solve part 2
+    fn part2(&self) -> Answer;
+
+    /// Solve one part, by number
+    fn solve(&self, part: u8) -> Answer {
+        match part {
+            1 => self.part1(),
+            _ => self.part2(),
+        }
+    }
+
+    /// Constants which can be changed with `--param`, read with `Param::value` when solving
+    fn params(&self) -> Vec<Param> {
+        vec![]
+    }
+
+    /// Check the assumptions about the input which the solution quietly relies on, so
+    /// `--validate` can say when an answer can't be trusted. Most days don't need any.
+    fn checks(&self) -> Vec<Check> {
+        vec![]
+    }
+}
+
+
+/// An assumption a solution makes about its input, and whether this input holds to it
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub assumption: String,
+    /// Why the input breaks the assumption, if it does
+    pub problem: Option<String>,
+}
+
+impl Check {
+    /// Assumption which holds if `ok`, otherwise broken for the reason given
+    pub fn new(assumption: &str, ok: bool, problem: impl FnOnce() -> String) -> Check {
+        Check{ assumption: String::from(assumption), problem: if ok { None } else { Some(problem()) } }
+    }
+
+    pub fn holds(&self) -> bool {
+        self.problem.is_none()
+    }
+}
+
+
+/// Parses puzzle input into a boxed solution
+pub type Parser = fn(&str) -> Result<Box<dyn Solution>>;
+
+
+/// Entry in the registry of solved days
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Parser,
+}
+
+impl Day {
+    pub const fn new(year: u32, day: u32, parse: Parser) -> Day {
+        Day{ year, day, parse }
+    }
+
+    /// Name of the real input for this day e.g. "day17"
+    pub fn input(&self) -> String {
+        format!("day{}", self.day)
+    }
+}
+
+
+/// Parser for a solution, as used in the registry, which normalises the input first
+pub fn boxed<S: Solution + 'static>(content: &str) -> Result<Box<dyn Solution>> {
+    Ok(Box::new(S::parse(&input::normalise(content))?))
+}
+
+
+/// Registered days for each event year, oldest first
+pub const YEARS: [&[Day]; 1] = [
+    &y2023::DAYS,
+];
+
+
+/// Every registered day of every year, in order
+pub fn all_days() -> impl Iterator<Item = Day> {
+    YEARS.into_iter().flatten().copied()
+}
+
+
+/// Every year with registered days
+pub fn years() -> Vec<u32> {
+    YEARS.iter().filter_map(|days| days.first()).map(|d| d.year).collect()
+}
+
+
+/// Most recent year, which is assumed when one isn't given
+pub fn latest_year() -> u32 {
+    years().into_iter().max().unwrap_or(2023)
+}
+
+
+/// Look up a registered day
+pub fn lookup(year: u32, day: u32) -> Option<Day> {
+    all_days().find(|d| (d.year == year) && (d.day == day))
+}
+
+
+/// Split an input like "2023/17_test2" into its year and input name e.g. "day17_test2",
+/// assuming the latest year if there isn't one
+pub fn parse_input(spec: &str) -> Option<(u32, String)> {
+    let spec = spec.trim();
+    let (year, name) = match spec.split_once('/') {
+        Some((year, name)) => (year.parse().ok()?, name),
+        None => (latest_year(), spec),
+    };
+    let name = name.strip_prefix("day").unwrap_or(name);
+    if name.is_empty() { return None; }
+    Some((year, format!("day{}", name)))
+}
\ No newline at end of file
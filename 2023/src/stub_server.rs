@@ -0,0 +1,84 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::thread::{self, JoinHandle};
+use std::{env, fs, process};
+
+
+/// Pretend Advent of Code site for testing the client without the network, which answers a
+/// single request
+pub struct StubServer {
+    pub base_url: String,
+    handle: JoinHandle<String>,
+}
+
+impl StubServer {
+    /// Start listening on a free local port, answering the first request with this status and body
+    pub fn serve_once(status: u16, body: &str) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("Can't start stub server");
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = String::from(body);
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().expect("No request to the stub server");
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line.trim().is_empty() { break; }
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8_lossy(&content));
+
+            let response = format!(
+                "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status, body.len(), body,
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+        StubServer{ base_url, handle }
+    }
+
+    /// Everything the client sent, once it's been answered
+    pub fn request(self) -> String {
+        self.handle.join().unwrap()
+    }
+}
+
+
+/// Empty directory for a test to write to, unique to the test and this run, which is removed
+/// again when the test is done with it
+pub struct TempDir(PathBuf);
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+
+/// Make a fresh `TempDir` for this test
+pub fn temp_dir(name: &str) -> TempDir {
+    let dir = env::temp_dir().join(format!("aoc-2023-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    TempDir(dir)
+}
\ No newline at end of file
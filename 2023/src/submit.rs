@@ -0,0 +1,323 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rug::Integer;
+
+use crate::config::Config;
+use crate::fetch::USER_AGENT;
+
+
+/// Every answer submitted so far, in the data dir for each year
+pub const HISTORY: &str = "submissions.txt";
+
+
+/// What the site made of a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way
+    Wrong,
+    /// Answered too recently, so the answer wasn't checked
+    RateLimited,
+    /// The part has already been solved
+    AlreadySolved,
+    /// Couldn't make sense of the response
+    Unknown,
+}
+
+impl Verdict {
+    /// Work out the verdict from the page the site sends back
+    pub fn from_response(body: &str) -> Verdict {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if body.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Name used in the history file
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Verdict> {
+        [
+            Verdict::Correct, Verdict::TooHigh, Verdict::TooLow, Verdict::Wrong,
+            Verdict::RateLimited, Verdict::AlreadySolved, Verdict::Unknown,
+        ].into_iter().find(|v| v.name() == name)
+    }
+
+    /// Was the answer checked and found to be wrong?
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited => "rate limited",
+            Verdict::AlreadySolved => "already solved",
+            Verdict::Unknown => "not understood",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+
+/// One submitted answer
+#[derive(Debug, Clone)]
+pub struct Attempt {
+    /// Seconds since the epoch
+    pub time: u64,
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+impl Attempt {
+    fn from_string(line: &str) -> Option<Attempt> {
+        let mut parts = line.split(" ");
+        let time = parts.next()?.parse().ok()?;
+        let input = String::from(parts.next()?);
+        let part = parts.next()?.parse().ok()?;
+        let answer = String::from(parts.next()?);
+        let verdict = Verdict::from_name(parts.next()?)?;
+        Some(Attempt{ time, input, part, answer, verdict })
+    }
+
+    fn to_line(&self) -> String {
+        format!("{} {} {} {} {}", self.time, self.input, self.part, self.answer, self.verdict.name())
+    }
+}
+
+
+/// Log of every answer submitted, one line per attempt e.g.
+///
+/// ```text
+/// 1701500000 day7 1 252295678 correct
+/// ```
+///
+/// Blank lines and lines starting with '#' are ignored
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Load from disk - a missing file is treated as empty
+    pub fn load(path: &Path) -> History {
+        let attempts = fs::read_to_string(path)
+            .unwrap_or_default()
+            .split("\n")
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(Attempt::from_string)
+            .collect();
+        History{ path: path.to_path_buf(), attempts }
+    }
+
+    /// Previous attempts at a part of an input file
+    pub fn attempts(&self, input: &str, part: u8) -> impl Iterator<Item = &Attempt> {
+        let input = String::from(input);
+        self.attempts.iter().filter(move |a| (a.input == input) && (a.part == part))
+    }
+
+    /// Explain why an answer isn't worth submitting, if it's already known to be wrong or is
+    /// outside the bounds given by earlier answers which were too high or too low
+    pub fn check(&self, input: &str, part: u8, answer: &str) -> Result<(), String> {
+        let value = answer.parse::<Integer>().ok();
+        for attempt in self.attempts(input, part) {
+            if attempt.verdict == Verdict::Correct {
+                return Err(format!("Already solved, the answer was {}", attempt.answer));
+            }
+            if attempt.verdict.is_wrong() && (attempt.answer == answer) {
+                return Err(format!("{} has already been tried and was {}", answer, attempt.verdict));
+            }
+            let (Some(value), Ok(bound)) = (&value, attempt.answer.parse::<Integer>()) else { continue };
+            if (attempt.verdict == Verdict::TooHigh) && (*value >= bound) {
+                return Err(format!("{} must be too high, as {} already was", answer, bound));
+            }
+            if (attempt.verdict == Verdict::TooLow) && (*value <= bound) {
+                return Err(format!("{} must be too low, as {} already was", answer, bound));
+            }
+        }
+        Ok(())
+    }
+
+    /// Add an attempt, appending it to the file straight away
+    pub fn add(&mut self, attempt: Attempt) -> io::Result<()> {
+        let new = !self.path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        if new {
+            writeln!(file, "# time input part answer verdict")?;
+        }
+        writeln!(file, "{}", attempt.to_line())?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+
+/// Submit an answer for a part of a day of a year, unless the history says it's bound to be wrong, and
+/// record the attempt. Returns the verdict, along with how long to wait if we were too quick.
+pub fn submit_answer(
+    config: &Config, history: &mut History, year: u32, day: u32, part: u8, answer: &str,
+) -> Result<(Verdict, Option<String>), String> {
+    let input = format!("day{}", day);
+    history.check(&input, part, answer)?;
+    let Some(session) = &config.session else {
+        return Err(String::from("No session token - add `session = <cookie>` to the config file"));
+    };
+
+    let url = format!("{}/{}/day/{}/answer", config.base_url.trim_end_matches('/'), year, day);
+    let response = ureq::post(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+    let body = match response {
+        Ok(response) => response.into_string().map_err(|e| format!("Can't read response from {}: {}", url, e))?,
+        Err(ureq::Error::Status(status, response)) => {
+            return Err(format!("{} returned {} {}", url, status, response.status_text()));
+        },
+        Err(e) => return Err(format!("Can't submit to {}: {}", url, e)),
+    };
+
+    let verdict = Verdict::from_response(&body);
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |t| t.as_secs());
+    history.add(Attempt{ time, input, part, answer: String::from(answer), verdict })
+        .map_err(|e| format!("Can't write {}: {}", history.path.display(), e))?;
+    Ok((verdict, wait_time(&body)))
+}
+
+
+/// How long the site says to wait before trying again e.g. "You have 4m 12s left to wait."
+fn wait_time(body: &str) -> Option<String> {
+    let start = body.find("You have ")?;
+    let end = start + body[start..].find("left to wait")? + "left to wait".len();
+    Some(format!("{}.", &body[start..end]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::{temp_dir, StubServer};
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict) -> Attempt {
+        Attempt{ time: 0, input: String::from("day7"), part, answer: String::from(answer), verdict }
+    }
+
+    fn history(attempts: Vec<Attempt>) -> History {
+        History{ path: PathBuf::from("unused"), attempts }
+    }
+
+    #[test]
+    fn verdict_from_response() {
+        let cases = [
+            ("<p>That's the right answer! You are one gold star closer.</p>", Verdict::Correct),
+            ("<p>That's not the right answer; your answer is too high.</p>", Verdict::TooHigh),
+            ("<p>That's not the right answer; your answer is too low.</p>", Verdict::TooLow),
+            ("<p>That's not the right answer. If you're stuck...</p>", Verdict::Wrong),
+            ("<p>You gave an answer too recently. You have 4m 12s left to wait.</p>", Verdict::RateLimited),
+            ("<p>You don't seem to be solving the right level.</p>", Verdict::AlreadySolved),
+            ("<p>Something else entirely</p>", Verdict::Unknown),
+        ];
+        for (body, verdict) in cases {
+            assert_eq!(Verdict::from_response(body), verdict, "{}", body);
+        }
+    }
+
+    #[test]
+    fn wait_time_from_response() {
+        let body = "<p>You gave an answer too recently. You have 4m 12s left to wait.</p>";
+        assert_eq!(wait_time(body).as_deref(), Some("You have 4m 12s left to wait."));
+        assert_eq!(wait_time("<p>That's the right answer!</p>"), None);
+    }
+
+    #[test]
+    fn check_allows_new_answer() {
+        let history = history(vec![attempt(1, "100", Verdict::Wrong), attempt(2, "50", Verdict::Correct)]);
+        assert!(history.check("day7", 1, "101").is_ok());
+        assert!(history.check("day8", 2, "50").is_ok());
+    }
+
+    #[test]
+    fn check_refuses_duplicate() {
+        let history = history(vec![attempt(1, "100", Verdict::Wrong)]);
+        let error = history.check("day7", 1, "100").unwrap_err();
+        assert_eq!(error, "100 has already been tried and was wrong");
+    }
+
+    #[test]
+    fn check_refuses_outside_bounds() {
+        let history = history(vec![attempt(1, "100", Verdict::TooHigh), attempt(1, "20", Verdict::TooLow)]);
+        assert_eq!(history.check("day7", 1, "150").unwrap_err(), "150 must be too high, as 100 already was");
+        assert_eq!(history.check("day7", 1, "15").unwrap_err(), "15 must be too low, as 20 already was");
+        assert!(history.check("day7", 1, "50").is_ok());
+    }
+
+    #[test]
+    fn check_refuses_solved() {
+        let history = history(vec![attempt(1, "42", Verdict::Correct)]);
+        assert_eq!(history.check("day7", 1, "43").unwrap_err(), "Already solved, the answer was 42");
+    }
+
+    #[test]
+    fn submits_and_records_answer() {
+        let server = StubServer::serve_once(200, "<p>That's the right answer! You are one gold star closer.</p>");
+        let config = Config{ session: Some(String::from("abc123")), base_url: server.base_url.clone() };
+        let dir = temp_dir("submit");
+        let path = dir.join(HISTORY);
+        let mut history = History::load(&path);
+        let (verdict, wait) = submit_answer(&config, &mut history, 2023, 7, 1, "252295678").unwrap();
+        let request = server.request();
+
+        assert_eq!(verdict, Verdict::Correct);
+        assert_eq!(wait, None);
+        assert!(request.starts_with("POST /2023/day/7/answer "), "{}", request);
+        assert!(request.to_lowercase().contains("cookie: session=abc123"), "{}", request);
+        assert!(request.ends_with("level=1&answer=252295678"), "{}", request);
+
+        let reloaded = History::load(&path);
+        let attempts = reloaded.attempts("day7", 1).collect::<Vec<_>>();
+        assert_eq!(attempts.len(), 1);
+        assert_eq!((attempts[0].answer.as_str(), attempts[0].verdict), ("252295678", Verdict::Correct));
+    }
+
+    #[test]
+    fn skips_known_wrong_answer() {
+        // nothing is listening here, so this would fail differently if it tried to submit
+        let config = Config{ session: Some(String::from("abc123")), base_url: String::from("http://127.0.0.1:1") };
+        let mut history = history(vec![attempt(1, "100", Verdict::TooHigh)]);
+        let error = submit_answer(&config, &mut history, 2023, 7, 1, "120").unwrap_err();
+        assert_eq!(error, "120 must be too high, as 100 already was");
+    }
+}
\ No newline at end of file
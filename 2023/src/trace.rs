@@ -0,0 +1,129 @@
+use std::fmt;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+
+/// How much diagnostic output to show, each level including the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    /// Occasional progress through a long search
+    Info,
+    /// What a solver is doing, step by step
+    Debug,
+    /// Everything, however much there is
+    Trace,
+}
+
+impl Level {
+    /// Level for a number of `-v`s, or the value it was stored as
+    pub fn from_verbosity(count: usize) -> Level {
+        match count {
+            0 => Level::Off,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Off => "OFF",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+
+/// Level shown for every target
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Targets like "day20" or "2023/day20" to show everything for, whatever the level
+static TARGETS: Mutex<Vec<String>> = Mutex::new(vec![]);
+
+/// Whether there are any targets, so checking doesn't need the lock in the usual case
+static HAS_TARGETS: AtomicBool = AtomicBool::new(false);
+
+
+/// Show messages up to this level from everywhere
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+
+/// Show every message from a target like "day20" (any year) or "2023/day20"
+pub fn add_target(target: &str) {
+    TARGETS.lock().unwrap().push(String::from(target.trim()));
+    HAS_TARGETS.store(true, Ordering::Relaxed);
+}
+
+
+/// Target for messages from a module, so "aoc_2023::y2023::day20" is "2023/day20" and anything
+/// outside a year is just the last part e.g. "runner"
+pub fn target(module_path: &str) -> String {
+    let mut parts = module_path.rsplit("::");
+    let last = parts.next().unwrap_or(module_path);
+    match parts.next().and_then(|y| y.strip_prefix('y')).filter(|y| y.parse::<u32>().is_ok()) {
+        Some(year) => format!("{}/{}", year, last),
+        None => String::from(last),
+    }
+}
+
+
+/// Would a message at this level from a module be shown? Worth checking before building any
+/// expensive output, like drawing a grid.
+pub fn enabled(level: Level, module_path: &str) -> bool {
+    if level <= Level::from_verbosity(LEVEL.load(Ordering::Relaxed) as usize) {
+        return true;
+    }
+    if !HAS_TARGETS.load(Ordering::Relaxed) {
+        return false;
+    }
+    let targets = TARGETS.lock().unwrap();
+    let target = target(module_path);
+    targets.iter().any(|t| (*t == target) || target.ends_with(&format!("/{}", t)))
+}
+
+
+/// Write a message to stderr, so stdout only ever has answers on it
+pub fn emit(level: Level, module_path: &str, message: fmt::Arguments) {
+    eprintln!("[{} {}] {}", target(module_path), level, message);
+}
+
+
+/// Show a message about progress through a long search, with `-v`
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Info, module_path!()) {
+            $crate::trace::emit($crate::trace::Level::Info, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+
+/// Show a message about what a solver is doing, with `-vv`
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Debug, module_path!()) {
+            $crate::trace::emit($crate::trace::Level::Debug, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+
+/// Show a message which there might be a great many of, with `-vvv` or `--trace`
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled($crate::trace::Level::Trace, module_path!()) {
+            $crate::trace::emit($crate::trace::Level::Trace, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
\ No newline at end of file
@@ -1,3 +1,8 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use crate::error::{AocError, Result};
+
 #[derive(Debug, Eq, Hash, PartialEq, Copy, Clone, PartialOrd, Ord)]
 pub struct Point {
     pub r: usize,
@@ -6,33 +11,268 @@ pub struct Point {
 
 impl Point {
     pub fn new(r: usize, c: usize) -> Point { Point{ r, c } }
-    pub fn up(&self, inc: usize) -> Point { Point{ r: self.r - inc, c: self.c } }
-    pub fn down(&self, inc: usize) -> Point { Point{ r: self.r + inc, c: self.c } }
-    pub fn left(&self, inc: usize) -> Point { Point{ r: self.r, c: self.c - inc } }
-    pub fn right(&self, inc: usize) -> Point { Point{ r: self.r, c: self.c + inc } }
 
-    pub fn direct_neighbors(&self, grid: &Grid) -> Vec<Point> {
-        let mut neighbors: Vec<Point> = vec![];
-        if self.r > 0 { neighbors.push(self.up(1)); }
-        if self.c > 0 { neighbors.push(self.left(1)); }
-        if self.r < grid.nrows - 1 { neighbors.push(self.down(1)); }
-        if self.c < grid.ncols - 1 { neighbors.push(self.right(1)); }
-        neighbors
+    /// Neighbors which are inside the grid
+    pub fn neighbors<T>(&self, grid: &Grid<T>, hood: Neighborhood) -> Vec<Point> {
+        hood.directions().iter().filter_map(|dir| self.step(*dir, grid)).collect()
     }
 
-    pub fn to_string(&self) -> String {
-        format!("({}, {})", self.r, self.c)
+    /// Neighbor in a direction, if it's still inside the grid
+    pub fn step<T>(&self, dir: Direction, grid: &Grid<T>) -> Option<Point> {
+        (Vec2::from(*self) + dir.delta()).in_grid(grid)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.r, self.c)
+    }
+}
+
+impl TryFrom<Vec2> for Point {
+    type Error = Vec2;
+
+    /// Grid index for a position, which fails if it's negative
+    fn try_from(v: Vec2) -> std::result::Result<Point, Vec2> {
+        match (usize::try_from(v.r), usize::try_from(v.c)) {
+            (Ok(r), Ok(c)) => Ok(Point::new(r, c)),
+            _ => Err(v),
+        }
+    }
+}
+
+
+/// Signed position (or offset between positions) in rows and columns, for working somewhere
+/// which isn't bounded by a grid. Convert to a `Point` to index into a grid.
+#[derive(Debug, Default, Eq, Hash, PartialEq, Copy, Clone, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub r: i64,
+    pub c: i64,
+}
+
+impl Vec2 {
+    pub const fn new(r: i64, c: i64) -> Vec2 { Vec2{ r, c } }
+
+    /// Distance moving only up, down, left and right
+    pub fn manhattan(&self, other: &Vec2) -> u64 {
+        self.r.abs_diff(other.r) + self.c.abs_diff(other.c)
     }
+
+    /// Neighbors anywhere, with no grid to stop at
+    pub fn neighbors(&self, hood: Neighborhood) -> Vec<Vec2> {
+        hood.directions().iter().map(|dir| *self + dir.delta()).collect()
+    }
+
+    /// Index into a grid, if this is inside it
+    pub fn in_grid<T>(&self, grid: &Grid<T>) -> Option<Point> {
+        Point::try_from(*self).ok().filter(|p| (p.r < grid.nrows) && (p.c < grid.ncols))
+    }
+}
+
+impl From<Point> for Vec2 {
+    fn from(p: Point) -> Vec2 {
+        Vec2{ r: p.r as i64, c: p.c as i64 }
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.r, self.c)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+    fn add(self, other: Vec2) -> Vec2 { Vec2{ r: self.r + other.r, c: self.c + other.c } }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) { *self = *self + other; }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+    fn sub(self, other: Vec2) -> Vec2 { Vec2{ r: self.r - other.r, c: self.c - other.c } }
 }
 
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Vec2) { *self = *self - other; }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+    fn neg(self) -> Vec2 { Vec2{ r: -self.r, c: -self.c } }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, n: i64) -> Vec2 { Vec2{ r: self.r * n, c: self.c * n } }
+}
+
+
+/// Compass direction on a grid, with up being towards row 0
+#[derive(Debug, Eq, Hash, PartialEq, Copy, Clone, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Up, down, left and right, going clockwise
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Just the diagonals, going clockwise
+    pub const DIAGONAL: [Direction; 4] = [Direction::UpRight, Direction::DownRight, Direction::DownLeft, Direction::UpLeft];
+
+    /// Every direction, going clockwise from up
+    pub const ALL: [Direction; 8] = [
+        Direction::Up, Direction::UpRight, Direction::Right, Direction::DownRight,
+        Direction::Down, Direction::DownLeft, Direction::Left, Direction::UpLeft,
+    ];
+
+    /// Turn clockwise by some number of eighths of a full turn
+    fn rotate(&self, eighths: usize) -> Direction {
+        Direction::ALL[(*self as usize + eighths) % 8]
+    }
+
+    /// Quarter turn anticlockwise
+    pub fn turn_left(&self) -> Direction { self.rotate(6) }
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Grid {
-    pub cells: Vec<Vec<char>>,
+    /// Quarter turn clockwise
+    pub fn turn_right(&self) -> Direction { self.rotate(2) }
+
+    /// Facing the other way
+    pub fn opposite(&self) -> Direction { self.rotate(4) }
+
+    pub fn is_vertical(&self) -> bool { matches!(self, Direction::Up | Direction::Down) }
+    pub fn is_horizontal(&self) -> bool { matches!(self, Direction::Left | Direction::Right) }
+
+    /// Offset of a single step in this direction
+    pub fn delta(&self) -> Vec2 {
+        match self {
+            Direction::Up => Vec2::new(-1, 0),
+            Direction::UpRight => Vec2::new(-1, 1),
+            Direction::Right => Vec2::new(0, 1),
+            Direction::DownRight => Vec2::new(1, 1),
+            Direction::Down => Vec2::new(1, 0),
+            Direction::DownLeft => Vec2::new(1, -1),
+            Direction::Left => Vec2::new(0, -1),
+            Direction::UpLeft => Vec2::new(-1, -1),
+        }
+    }
+
+    /// Direction of a single step, if it is one
+    pub fn from_delta(delta: Vec2) -> Option<Direction> {
+        Direction::ALL.into_iter().find(|d| d.delta() == delta)
+    }
+
+    /// Parse an arrow like '^' or 'v'
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Parse a letter, either 'U', 'D', 'L', 'R' or a compass point like 'N'
+    pub fn from_letter(c: char) -> Option<Direction> {
+        match c {
+            'U' | 'N' => Some(Direction::Up),
+            'D' | 'S' => Some(Direction::Down),
+            'L' | 'W' => Some(Direction::Left),
+            'R' | 'E' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Parse a digit counting clockwise from right, so '0' is right and '3' is up
+    pub fn from_digit(c: char) -> Option<Direction> {
+        match c {
+            '0' => Some(Direction::Right),
+            '1' => Some(Direction::Down),
+            '2' => Some(Direction::Left),
+            '3' => Some(Direction::Up),
+            _ => None,
+        }
+    }
+
+    /// Arrow for drawing on a grid
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::UpRight => '↗',
+            Direction::Right => '>',
+            Direction::DownRight => '↘',
+            Direction::Down => 'v',
+            Direction::DownLeft => '↙',
+            Direction::Left => '<',
+            Direction::UpLeft => '↖',
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+
+/// Which cells count as next to each other
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// Up, down, left and right
+    Orthogonal,
+    /// All 8 around, like a king in chess
+    All,
+}
+
+impl Neighborhood {
+    pub fn directions(&self) -> &'static [Direction] {
+        match self {
+            Neighborhood::Orthogonal => &Direction::ORTHOGONAL,
+            Neighborhood::All => &Direction::ALL,
+        }
+    }
+}
+
+
+/// Rectangular grid of cells, which are chars unless parsed into something else. The cells are
+/// stored row by row in one `Vec`, so comparing, hashing and cloning a grid is cheap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T = char> {
+    cells: Vec<T>,
     pub nrows: usize,
     pub ncols: usize,
 }
 
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self.rows()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect::<Vec<String>>();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+    fn index(&self, p: Point) -> &T { &self.cells[self.offset(&p)] }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T { let i = self.offset(&p); &mut self.cells[i] }
+}
+
 pub enum GridRotation {
     Flip,
     Left,
@@ -40,67 +280,160 @@ pub enum GridRotation {
 }
 
 impl Grid {
-    /// New grid from grid of chars
-    pub fn new(cells: Vec<Vec<char>>) -> Grid {
-        let nrows = cells.len();
-        let ncols = cells[0].len();
-        Grid { cells, nrows, ncols }
+    /// New grid from input string, which has to be rectangular
+    pub fn from_string(content: &str) -> Result<Grid> {
+        Grid::parse_with(content, Some)
     }
 
-    /// New grid from input string
-    pub fn from_string(content: &str) -> Grid {
-        let cells: Vec<Vec<char>> = content.split("\n")
-            .map(|line| line.trim().chars().collect())
-            .collect();
-        Grid::new(cells)
+    /// Check every cell is one of the allowed characters
+    pub fn check_chars(&self, allowed: &str) -> Result<()> {
+        for (r, row) in self.rows().enumerate() {
+            for (c, cell) in row.iter().enumerate() {
+                if !allowed.contains(*cell) {
+                    return Err(AocError::new(format!("unexpected character {:?}", cell)).at(r, c));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// New grid from rows of cells
+    pub fn new(rows: Vec<Vec<T>>) -> Grid<T> {
+        let nrows = rows.len();
+        let ncols = rows[0].len();
+        Grid::from_cells(rows.into_iter().flatten().collect(), nrows, ncols)
     }
 
-    /// New grid from concatenating other grids - used in day 21 for testing
-    pub fn replicate(&self, shape: (usize, usize)) -> Grid {
-        let mut cells = vec![];
-        for _ in 0..shape.0 {
-            let mut row_cells: Vec<Vec<char>> = vec![vec![]; self.nrows];
-            for _ in 0..shape.1 {
-                for (i, row) in self.cells.iter().enumerate() {
-                    row_cells[i].extend(row.iter());
-                }
+    /// New grid from cells in row order
+    pub fn from_cells(cells: Vec<T>, nrows: usize, ncols: usize) -> Grid<T> {
+        assert_eq!(cells.len(), nrows * ncols, "{} cells can't make a {}x{} grid", cells.len(), nrows, ncols);
+        Grid { cells, nrows, ncols }
+    }
+
+    /// New grid from input string, which has to be rectangular, turning each character into a
+    /// cell e.g. `Grid::parse_with(content, |c| c.to_digit(10))`. Characters which don't make a
+    /// cell are an error.
+    pub fn parse_with(content: &str, parse: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+        let lines = content.split("\n").map(|line| line.trim()).collect::<Vec<_>>();
+        let ncols = lines[0].chars().count();
+        if ncols == 0 {
+            return Err(AocError::new("empty grid").at(0, 0));
+        }
+        let mut cells = Vec::with_capacity(lines.len() * ncols);
+        for (r, line) in lines.iter().enumerate() {
+            let len = line.chars().count();
+            if len != ncols {
+                let message = format!("expected {} columns, found {}", ncols, len);
+                return Err(AocError::new(message).at(r, len.min(ncols)));
+            }
+            for (c, ch) in line.chars().enumerate() {
+                let cell = parse(ch).ok_or_else(|| AocError::new(format!("unexpected character {:?}", ch)).at(r, c))?;
+                cells.push(cell);
             }
-            cells.extend(row_cells);
         }
-        Grid::new(cells)
+        Ok(Grid::from_cells(cells, lines.len(), ncols))
+    }
+
+    /// New grid with every cell converted to another type
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid::from_cells(self.cells.iter().map(f).collect(), self.nrows, self.ncols)
+    }
+
+    /// Where a point's cell is in the cells
+    fn offset(&self, p: &Point) -> usize {
+        assert!(p.c < self.ncols, "column {} is outside the grid", p.c);
+        p.r * self.ncols + p.c
+    }
+
+    pub fn get(&self, p: &Point) -> &T {
+        &self.cells[self.offset(p)]
+    }
+
+    /// Cell next to a point in a direction, along with where it is, if it's inside the grid
+    pub fn look(&self, p: &Point, dir: Direction) -> Option<(Point, &T)> {
+        p.step(dir, self).map(|next| (next, self.get(&next)))
+    }
+
+    /// Neighboring cells which are inside the grid, along with where they are
+    pub fn neighbors(&self, p: &Point, hood: Neighborhood) -> impl Iterator<Item = (Point, &T)> {
+        p.neighbors(self, hood).into_iter().map(|n| (n, self.get(&n)))
+    }
+
+    /// Point in the grid for any position, as if copies of the grid were tiled forever in
+    /// every direction
+    pub fn wrap(&self, v: &Vec2) -> Point {
+        Point::new(v.r.rem_euclid(self.nrows as i64) as usize, v.c.rem_euclid(self.ncols as i64) as usize)
     }
 
-    pub fn to_string(&self) -> String {
-        self.cells
-            .iter()
-            .map(|row| row.into_iter().collect())
-            .collect::<Vec<String>>()
-            .join("\n")
+    /// Cell at any position in a grid tiled forever in every direction
+    pub fn tiled(&self, v: &Vec2) -> &T {
+        self.get(&self.wrap(v))
     }
 
-    pub fn get(&self, p: &Point) -> &char {
-        &self.cells[p.r][p.c]
+    /// Every cell, row by row
+    pub fn cells(&self) -> &[T] {
+        &self.cells
     }
 
-    pub fn getnum(&self, p: &Point) -> usize {
-        self.get(p).to_digit(10).unwrap() as usize
+    pub fn row(&self, r: usize) -> &[T] {
+        &self.cells[r * self.ncols..(r + 1) * self.ncols]
     }
 
-    pub fn rotate(&self, dir: GridRotation) -> Grid {
-        let mut rotated = match dir {
-            GridRotation::Flip => self.cells.clone(),
-            _ => vec![vec!['.'; self.nrows]; self.ncols],
+    pub fn row_mut(&mut self, r: usize) -> &mut [T] {
+        &mut self.cells[r * self.ncols..(r + 1) * self.ncols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.ncols)
+    }
+
+    /// Cells in a column, top to bottom
+    pub fn col(&self, c: usize) -> impl Iterator<Item = &T> {
+        assert!(c < self.ncols, "column {} is outside the grid", c);
+        self.cells[c..].iter().step_by(self.ncols)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn rotate(&self, dir: GridRotation) -> Grid<T> {
+        let (nrows, ncols) = match dir {
+            GridRotation::Flip => (self.nrows, self.ncols),
+            _ => (self.ncols, self.nrows),
         };
-        for r in 0..self.nrows {
-            for c in 0..self.ncols {
-                let (newr, newc) = match dir {
-                    GridRotation::Flip => { (self.nrows - r - 1, self.ncols - c - 1) },
-                    GridRotation::Left => { (self.ncols - c - 1, r) },
-                    GridRotation::Right => { (c, self.nrows - r - 1) },
+        let rotated = (0..nrows)
+            .flat_map(|newr| (0..ncols).map(move |newc| (newr, newc)))
+            .map(|(newr, newc)| {
+                // where this cell came from
+                let (r, c) = match dir {
+                    GridRotation::Flip => { (self.nrows - newr - 1, self.ncols - newc - 1) },
+                    GridRotation::Left => { (newc, self.ncols - newr - 1) },
+                    GridRotation::Right => { (self.nrows - newc - 1, newr) },
                 };
-                rotated[newr][newc] = self.cells[r][c];
-            }
-        }
-        Grid::new(rotated)
+                self.cells[r * self.ncols + c].clone()
+            })
+            .collect();
+        Grid::from_cells(rotated, nrows, ncols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexes_rows_and_columns() {
+        let grid = Grid::from_string("abc\ndef").unwrap();
+        assert_eq!(grid[Point::new(0, 2)], 'c');
+        assert_eq!(grid[Point::new(1, 0)], 'd');
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is outside the grid")]
+    fn rejects_column_past_the_end() {
+        // would be 'd' at the start of the next row without the check
+        let grid = Grid::from_string("abc\ndef").unwrap();
+        let _ = grid[Point::new(0, 3)];
     }
 }
\ No newline at end of file
@@ -0,0 +1,72 @@
+// maybe 40 mins, can't remember?
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, AocError, Result};
+use crate::solution::Solution;
+
+pub struct Day1 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day1 {
+    fn parse(content: &str) -> Result<Day1> {
+        let lines = parse_lines(content, |line| {
+            let line = line.trim();
+            let has_digit = line.chars().any(|c| c.is_ascii_digit())
+                || VALUES.iter().any(|(_, name)| line.contains(name));
+            if !has_digit {
+                return Err(AocError::new("no digit or digit word"));
+            }
+            Ok(String::from(line))
+        })?;
+        Ok(Day1{ lines })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut total: u32 = 0;
+        for part in self.lines.iter() {
+            let digits: Vec<u32> =
+                part.chars()
+                .filter_map(|c| c.to_digit(10))
+                .collect();
+            if digits.is_empty() {
+                continue;
+            }
+            let num: u32 = 10 * digits.first().unwrap() + digits.last().unwrap();
+            total += num;
+        }
+        Answer::from(total)
+    }
+
+    fn part2(&self) -> Answer {
+        let mut total: i32 = 0;
+        for part in self.lines.iter() {
+            let mut start = -1;
+            let mut end = -1;
+            for index in 0..part.len() {
+                for (num, name) in VALUES {
+                  if part[index..].starts_with(&num.to_string()) | part[index..].starts_with(name) {
+                      if start < 0 { start = num };
+                      end = num;
+                  }
+                }
+            }
+            total += start * 10 + end;
+        }
+        Answer::from(total)
+    }
+}
+
+
+const VALUES: [(i32, &str); 10] = [
+    ( 0, "zero" ),
+    ( 1, "one" ),
+    ( 2, "two" ),
+    ( 3, "three" ),
+    ( 4, "four" ),
+    ( 5, "five" ),
+    ( 6, "six" ),
+    ( 7, "seven" ),
+    ( 8, "eight" ),
+    ( 9, "nine" ),
+];
\ No newline at end of file
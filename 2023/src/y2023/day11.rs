@@ -0,0 +1,94 @@
+// 30 mins part 1, 5 mins part 2 :)
+
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::params::Param;
+use crate::solution::Solution;
+use crate::utils::{Grid, Point, Vec2};
+
+pub struct Day11 {
+    grid: Grid,
+}
+
+impl Solution for Day11 {
+    fn parse(content: &str) -> Result<Day11> {
+        let grid = Grid::from_string(content)?;
+        grid.check_chars(".#")?;
+        Ok(Day11{ grid })
+    }
+
+    fn part1(&self) -> Answer {
+        Answer::from(solve(&self.grid, EXPANSION.value()))
+    }
+
+    fn part2(&self) -> Answer {
+        Answer::from(solve(&self.grid, OLDER_EXPANSION.value()))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![EXPANSION, OLDER_EXPANSION]
+    }
+}
+
+
+const EXPANSION: Param = Param::new("expansion", 2, "how many times bigger empty rows and columns get in part 1").at_least(1);
+const OLDER_EXPANSION: Param = Param::new("older_expansion", 1_000_000, "how many times bigger they get in part 2").at_least(1);
+
+
+fn solve(grid: &Grid, expansion: usize) -> u64 {
+    let planets = fetch_planets(grid, expansion);
+    let mut dists = 0u64;
+    for i in 0..planets.len() {
+        for j in i + 1..planets.len() {
+            dists += Vec2::from(planets[i]).manhattan(&Vec2::from(planets[j]));
+        }
+    }
+    dists
+}
+
+
+fn fetch_planets(grid: &Grid, expansion: usize) -> Vec<Point> {
+    // 1. find non empty rows and cols
+    let mut planets: Vec<Point> = vec![];
+    let mut non_empty_rows: HashSet<usize> = HashSet::new();
+    let mut non_empty_cols: HashSet<usize> = HashSet::new();
+    for (r, row) in grid.rows().enumerate() {
+        for (c, cell) in row.iter().enumerate() {
+            if *cell == '#' {
+                planets.push(Point::new(r, c));
+                non_empty_rows.insert(r);
+                non_empty_cols.insert(c);
+            }
+        }
+    }
+    // 2. get new rows
+    let mut newr = vec![0usize; grid.nrows];
+    let mut offset = 0usize;
+    for (r, new) in newr.iter_mut().enumerate() {
+        if non_empty_rows.contains(&r) {
+            *new = r + offset;
+        } else {
+            offset += expansion - 1;
+        }
+    }
+    // 3. get new cols
+    let mut newc= vec![0usize; grid.ncols];
+    offset = 0;
+    for (c, new) in newc.iter_mut().enumerate() {
+        if non_empty_cols.contains(&c) {
+            *new = c + offset;
+        } else {
+            offset += expansion - 1;
+        }
+    }
+    // 4. re-map planet coords
+    for i in 0..planets.len() {
+        planets[i] = Point::new(
+            newr[planets[i].r],
+            newc[planets[i].c],
+        );
+    }
+    planets
+}
\ No newline at end of file
@@ -3,28 +3,41 @@
 use std::collections::{HashSet, HashMap};
 use itertools::Itertools;
 
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, split_pair, AocError, Result};
+use crate::params::Param;
+use crate::solution::Solution;
 
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content);
+pub struct Day12 {
+    records: Vec<Record>,
 }
 
+impl Solution for Day12 {
+    fn parse(content: &str) -> Result<Day12> {
+        Ok(Day12{ records: to_records(content)? })
+    }
 
-fn part1(content: &str) {
-    let records = to_records(content);
-    println!("PART 1: {}", solve(&records));
-}
+    fn part1(&self) -> Answer {
+        Answer::from(solve(&self.records))
+    }
 
+    fn part2(&self) -> Answer {
+        let records: Vec<Record> = self.records
+            .iter()
+            .map(|r| r.unfold(UNFOLD.value()))
+            .collect();
+        Answer::from(solve(&records))
+    }
 
-fn part2(content: &str) {
-    let records: Vec<Record> = to_records(content)
-        .iter()
-        .map(|r| r.unfold())
-        .collect();
-    println!("PART 2: {}", solve(&records));
+    fn params(&self) -> Vec<Param> {
+        vec![UNFOLD]
+    }
 }
 
 
+const UNFOLD: Param = Param::new("unfold", 5, "copies of each record after unfolding in part 2");
+
+
 fn solve(records: &[Record]) -> usize {
     records.iter()
         .map(|r| r.arrangements())
@@ -39,21 +52,24 @@ struct Record {
 }
 
 impl Record {
-    fn new(line: &str) -> Record {
-        let parts: Vec<&str> = line.trim().split(" ").collect();
-        Record{
-            springs: parts[0].chars().collect(),
-            groups: parts[1].split(",").map(|v| v.parse().unwrap()).collect(),
+    fn new(line: &str) -> Result<Record> {
+        let (springs, groups) = split_pair(line.trim(), " ")?;
+        if !springs.chars().all(|c| ".#?".contains(c)) {
+            return Err(AocError::invalid("springs", springs));
         }
+        Ok(Record{
+            springs: springs.chars().collect(),
+            groups: groups.split(",").map(parse_num).collect::<Result<_>>()?,
+        })
     }
 
-    fn unfold(&self) -> Record {
+    fn unfold(&self, copies: usize) -> Record {
         let mut springs: Vec<char> = vec![];
         let mut groups: Vec<usize> = vec![];
-        for i in 0..5 {
+        for i in 0..copies {
             springs.extend(&self.springs);
             groups.extend(&self.groups);
-            if i < 4 { springs.push('?') }
+            if i + 1 < copies { springs.push('?') }
         }
         Record{ springs, groups }
     }
@@ -87,7 +103,7 @@ impl Record {
                 continue;
             } else {
                 let part = &springs[i..i + group];
-                if (part.len() == group) & !self.any_known(&part, '.') {
+                if (part.len() == group) & !self.any_known(part, '.') {
 
                     // last group and no known damaged after this point
                     if (groups.len() == 1) & !self.any_known(&springs[i + group..], '#') {
@@ -95,14 +111,12 @@ impl Record {
                     }
 
                     // handle remaining groups
-                    else if springs.len() > i + group + 1 {
-                        if springs[i + group] != '#' {
-                            counts += self.find_arrangements(
-                                &springs[i + group + 1..], 
-                                &groups[1..],
-                                cache,
-                            )
-                        }
+                    else if (springs.len() > i + group + 1) && (springs[i + group] != '#') {
+                        counts += self.find_arrangements(
+                            &springs[i + group + 1..],
+                            &groups[1..],
+                            cache,
+                        )
                     }
                 }
                 // can't proceed past a known damaged spring
@@ -116,7 +130,7 @@ impl Record {
     }
 
     fn any_known(&self, springs: &[char], type_: char) -> bool {
-        springs.iter().any(|&c| c == type_)
+        springs.contains(&type_)
     }
 
     fn to_key(&self, springs: &[char], groups: &[usize]) -> String {
@@ -153,7 +167,7 @@ impl Record {
                         .map(|x|
                             if combo.contains(&x.0) { '#' }
                             else if *x.1 == '?' { '.' }
-                            else { x.1.clone() }
+                            else { *x.1 }
                         )
                         .collect();
                     if self._is_valid(poss) { 1usize } else { 0usize }
@@ -169,16 +183,16 @@ impl Record {
             if spring == '#' {
                 buffer += 1;
             } else {
-                if buffer > 0 { groups.push(buffer.clone()) };
+                if buffer > 0 { groups.push(buffer) };
                 buffer = 0;
             }
         }
-        if buffer > 0 { groups.push(buffer.clone()) };
+        if buffer > 0 { groups.push(buffer) };
         groups == self.groups
     }
 }
 
 
-fn to_records(content: &str) -> Vec<Record> {
-    content.split("\n").map(Record::new).collect()
+fn to_records(content: &str) -> Result<Vec<Record>> {
+    parse_lines(content, Record::new)
 }
\ No newline at end of file
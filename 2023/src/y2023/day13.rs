@@ -1,24 +1,29 @@
 // ~1 hour all in...bloody fiddly
 
+use crate::answer::Answer;
+use crate::error::{sections, Result};
+use crate::solution::Solution;
 use crate::utils::Grid;
 
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content);
+pub struct Day13 {
+    grids: Vec<Grid>,
 }
 
+impl Solution for Day13 {
+    fn parse(content: &str) -> Result<Day13> {
+        Ok(Day13{ grids: parse_content(content)? })
+    }
 
-fn part1(content: &str) {
-    let grids = parse_content(content);
-    let solution: usize = grids.iter().map(|g| solve(g, &0)).sum();
-    println!("PART 1: {}", solution);
-}
-
+    fn part1(&self) -> Answer {
+        let solution: usize = self.grids.iter().map(|g| solve(g, &0)).sum();
+        Answer::from(solution)
+    }
 
-fn part2(content: &str) {
-    let mut grids = parse_content(content);
-    let solution: usize = grids.iter_mut().map(solve_smudge).sum();
-    println!("PART 2: {}", solution);
+    fn part2(&self) -> Answer {
+        let mut grids = self.grids.clone();
+        let solution: usize = grids.iter_mut().map(solve_smudge).sum();
+        Answer::from(solution)
+    }
 }
 
 
@@ -27,14 +32,14 @@ fn solve_smudge(grid: &mut Grid) -> usize {
     for r in 0..grid.nrows {
         for c in 0..grid.ncols {
             // flip
-            grid.cells[r][c] = if grid.cells[r][c] == '.' { '#' } else { '.' };
+            grid.row_mut(r)[c] = if grid.row(r)[c] == '.' { '#' } else { '.' };
             // check
             let solution = solve(grid, &original);
             if (solution > 0) & (solution != original) {
                 return solution;
             }
             // unflip
-            grid.cells[r][c] = if grid.cells[r][c] == '.' { '#' } else { '.' };
+            grid.row_mut(r)[c] = if grid.row(r)[c] == '.' { '#' } else { '.' };
         }
     }
     0
@@ -79,26 +84,25 @@ fn solve(grid: &Grid, original: &usize) -> usize {
 
 
 fn cols_equal(grid: &Grid, c1: usize, c2: usize) -> bool {
-    grid.cells.iter().all(|r| r[c1] == r[c2])
+    grid.col(c1).eq(grid.col(c2))
 }
 
 
 fn rows_equal(grid: &Grid, r1: usize, r2: usize) -> bool {
-    grid.cells[r1] == grid.cells[r2]
+    grid.row(r1) == grid.row(r2)
 }
 
 
-fn parse_content(content: &str) -> Vec<Grid> {
-    let mut grids: Vec<Grid> = vec![];
-    let mut buffer: Vec<&str> = vec![];
-    for row in content.split("\n") {
-        if row == "" {
-            grids.push(Grid::from_string(&buffer.join("\n")));
-            buffer = vec![];
-        } else {
-            buffer.push(row);
-        }
-    }
-    grids.push(Grid::from_string(&buffer.join("\n")));
-    grids
+fn parse_content(content: &str) -> Result<Vec<Grid>> {
+    sections(content)
+        .iter()
+        .map(|(start, section)| parse_grid(section).map_err(|e| e.below(*start)))
+        .collect()
+}
+
+
+fn parse_grid(section: &str) -> Result<Grid> {
+    let grid = Grid::from_string(section)?;
+    grid.check_chars(".#")?;
+    Ok(grid)
 }
\ No newline at end of file
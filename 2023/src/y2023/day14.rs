@@ -0,0 +1,105 @@
+// 1 hour part 1, 1 hour part 2
+
+use std::collections::HashMap;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::params::Param;
+use crate::solution::Solution;
+use crate::utils::{Grid, GridRotation};
+
+pub struct Day14 {
+    grid: Grid,
+}
+
+impl Solution for Day14 {
+    fn parse(content: &str) -> Result<Day14> {
+        let grid = Grid::from_string(content)?;
+        grid.check_chars(".#O")?;
+        Ok(Day14{ grid })
+    }
+
+    fn part1(&self) -> Answer {
+        let grid = self.grid.rotate(GridRotation::Left);
+        let grid = tilt(&grid)
+            .rotate(GridRotation::Right);
+        Answer::from(score(&grid))
+    }
+
+    fn part2(&self) -> Answer {
+        let mut grid = self.grid.clone();
+        let mut history = vec![];
+        let mut seen: HashMap<Grid, usize> = HashMap::new();
+        let loop_start = loop {
+            if let Some(&start) = seen.get(&grid) {
+                break start;
+            }
+            seen.insert(grid.clone(), history.len());
+            history.push(grid.clone());
+            grid = cycle(&grid);
+        };
+        let ncycles = CYCLES.value();
+        let warmup = history[..loop_start].len();
+        let loop_size = history[loop_start..].len();
+        let last = if ncycles < warmup {
+            &history[ncycles]
+        } else {
+            &history[warmup + (ncycles - warmup) % loop_size]
+        };
+        Answer::from(score(last))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![CYCLES]
+    }
+}
+
+
+const CYCLES: Param = Param::new("cycles", 1_000_000_000, "spin cycles to run in part 2");
+
+
+fn cycle(grid: &Grid) -> Grid {
+    let grid = tilt(&grid.rotate(GridRotation::Left));
+    let grid = tilt(&grid.rotate(GridRotation::Right));
+    let grid = tilt(&grid.rotate(GridRotation::Right));
+    let grid = tilt(&grid.rotate(GridRotation::Right));
+    grid.rotate(GridRotation::Flip)
+}
+
+
+fn tilt(grid: &Grid) -> Grid {
+    // roll all 'O' to the left
+    let mut tilted = grid.clone();
+    for (r, row) in grid.rows().enumerate() {
+        let mut buffer: Vec<char> = vec![];
+        for (i, c) in row.iter().enumerate() {
+            if (*c == '#') & !buffer.is_empty() {
+                buffer.sort();  // sorts '.' before 'O'
+                for (offset, c) in buffer.iter().enumerate() {
+                    tilted.row_mut(r)[i - offset - 1] = *c;
+                }
+                buffer = vec![];
+            } else if *c != '#' {
+                buffer.push(*c);
+            }
+        }
+        if !buffer.is_empty() {
+            buffer.sort();  // sorts '.' before 'O'
+            for (offset, c) in buffer.iter().enumerate() {
+                tilted.row_mut(r)[grid.ncols - offset - 1] = *c;
+            }
+        }
+    }
+    tilted
+}
+
+
+fn score(grid: &Grid) -> usize {
+    let mut score = 0usize;
+    for (offset, row) in grid.rows().enumerate() {
+        let mult = grid.nrows - offset;
+        let nrocks: usize = row.iter().filter(|c| **c == 'O').count();
+        score += mult * nrocks;
+    }
+    score
+}
\ No newline at end of file
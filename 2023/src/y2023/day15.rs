@@ -0,0 +1,121 @@
+// 45 mins all in
+
+use crate::answer::Answer;
+use crate::error::{parse_num, AocError, Result};
+use crate::solution::Solution;
+
+pub struct Day15 {
+    steps: Vec<Step>,
+}
+
+impl Solution for Day15 {
+    fn parse(content: &str) -> Result<Day15> {
+        let steps = content
+            .split(",")
+            .map(Step::from_string)
+            .collect::<Result<_>>()
+            .map_err(|e| e.on_line(0, content))?;
+        Ok(Day15{ steps })
+    }
+
+    fn part1(&self) -> Answer {
+        let solution: usize = self.steps.iter().map(|s| to_hash(&s.text)).sum();
+        Answer::from(solution)
+    }
+
+    fn part2(&self) -> Answer {
+        let boxes = install(&self.steps);
+        Answer::from(calc_focusing_power(boxes))
+    }
+}
+
+
+/// Step of the initialization sequence e.g. "rn=1" to add a lens, or "cm-" to remove one
+#[derive(Debug, Clone)]
+struct Step {
+    text: String,
+    label: String,
+    focal_length: Option<usize>,
+}
+
+impl Step {
+    fn from_string(step: &str) -> Result<Step> {
+        let (label, focal_length) = if let Some((label, focal_length)) = step.split_once('=') {
+            (label, Some(parse_num(focal_length)?))
+        } else if let Some(label) = step.strip_suffix('-') {
+            (label, None)
+        } else {
+            return Err(AocError::invalid("step", step));
+        };
+        Ok(Step{ text: String::from(step), label: String::from(label), focal_length })
+    }
+}
+
+
+#[derive(Debug, Clone)]
+struct Lens {
+    label: String,
+    focal_length: usize,
+}
+
+impl Lens {
+    fn new(label: &str, focal_length: usize) -> Lens {
+        Lens{ label: String::from(label), focal_length }
+    }
+}
+
+
+fn install(steps: &[Step]) -> Vec<Vec<Lens>> {
+    let mut boxes: Vec<Vec<Lens>> = vec![vec![]; 256];
+    for step in steps.iter() {
+        let box_num = to_hash(&step.label);
+        // update or add lens
+        if let Some(focal_length) = step.focal_length {
+            let lens = Lens::new(&step.label, focal_length);
+            let mut found = false;
+            for (i, existing) in boxes[box_num].iter().enumerate() {
+                if existing.label == lens.label {
+                    found = true;
+                    boxes[box_num][i] = lens.clone();
+                    break;
+                }
+            }
+            if !found {
+                boxes[box_num].push(lens);
+            }
+        // or remove lens
+        } else {
+            let list = boxes[box_num].clone();
+            for (i, existing) in list.iter().enumerate() {
+                if existing.label == step.label {
+                    boxes[box_num].remove(i);
+                }
+            }
+        }
+    }
+    boxes
+}
+
+
+fn to_hash(step: &str) -> usize {
+    let mut value = 0usize;
+    for c in step.chars() {
+        value = ((value + c as usize) * 17) % 256;
+    }
+    value
+}
+
+
+fn calc_focusing_power(boxes: Vec<Vec<Lens>>) -> usize {
+    boxes.iter()
+        .enumerate()
+        .map(
+            |x| x.1.iter()
+                .enumerate()
+                .map(|y|
+                    (x.0 + 1) * (y.0 + 1) * y.1.focal_length
+                )
+                .sum::<usize>()
+        )
+        .sum()
+}
\ No newline at end of file
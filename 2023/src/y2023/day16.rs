@@ -0,0 +1,132 @@
+// Maybe an hour for part 1, then 10 mins for part 2
+
+use std::collections::{HashSet, VecDeque};
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::debug;
+use crate::solution::Solution;
+use crate::trace::{self, Level};
+use crate::utils::{Direction, Grid, Point};
+
+pub struct Day16 {
+    grid: Grid,
+}
+
+impl Solution for Day16 {
+    fn parse(content: &str) -> Result<Day16> {
+        let grid = Grid::from_string(content)?;
+        grid.check_chars(".|-/\\")?;
+        Ok(Day16{ grid })
+    }
+
+    fn part1(&self) -> Answer {
+        let start = Beam::new(0, 0, Direction::Right);
+        let energised = run_contraption(&self.grid, start);
+        Answer::from(energised)
+    }
+
+    fn part2(&self) -> Answer {
+        let grid = &self.grid;
+        let mut starts: Vec<Beam> = vec![];
+        starts.extend((0..grid.ncols).map(|c| Beam::new(0, c, Direction::Down)).collect_vec());
+        starts.extend((0..grid.ncols).map(|c| Beam::new(grid.nrows - 1, c, Direction::Up)).collect_vec());
+        starts.extend((0..grid.nrows).map(|r| Beam::new(r, 0, Direction::Right)).collect_vec());
+        starts.extend((0..grid.nrows).map(|r| Beam::new(r, grid.ncols - 1, Direction::Left)).collect_vec());
+        let energised: usize = starts.iter()
+            .map(|start| run_contraption(grid, *start))
+            .max()
+            .unwrap();
+        Answer::from(energised)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Beam{
+    loc: Point,
+    dir: Direction,
+}
+
+impl Beam {
+    fn new(r: usize, c: usize, dir: Direction) -> Beam {
+        Beam{ loc: Point::new(r, c), dir }
+    }
+
+    /// Change location
+    fn change_loc(&self, loc: Point) -> Beam {
+        Beam{ loc, dir: self.dir }
+    }
+
+    /// Change direction
+    fn change_dir(&self, dir: Direction) -> Beam {
+        Beam{ loc: self.loc, dir }
+    }
+
+    /// Move the beam a step in the direction its facing, or None if it falls off the grid
+    fn mv(&self, grid: &Grid) -> Option<Beam> {
+        self.loc.step(self.dir, grid).map(|loc| self.change_loc(loc))
+    }
+
+    /// Turn or split the beam based on the mirror in this cell
+    fn turn(&self, grid: &Grid) -> Vec<Beam> {
+        let mut beams: Vec<Beam> = vec![];
+        let mirror = *grid.get(&self.loc);
+        let dir = self.dir;
+        match mirror {
+            '/' => beams.push(self.change_dir(if dir.is_vertical() { dir.turn_right() } else { dir.turn_left() })),
+            '\\' => beams.push(self.change_dir(if dir.is_vertical() { dir.turn_left() } else { dir.turn_right() })),
+            '|' if dir.is_horizontal() => {
+                beams.push(self.change_dir(Direction::Up));
+                beams.push(self.change_dir(Direction::Down));
+            },
+            '-' if dir.is_vertical() => {
+                beams.push(self.change_dir(Direction::Left));
+                beams.push(self.change_dir(Direction::Right));
+            },
+            _ => { beams.push(*self) },
+        }
+        beams
+    }
+}
+
+
+/// Run the contraption, based on some starting beam, drawing the beams when debugging
+fn run_contraption(grid: &Grid, start: Beam) -> usize {
+    let mut energised: HashSet<Point> = HashSet::new();
+    let mut active: VecDeque<Beam> = VecDeque::from([start]);
+    let mut visited: HashSet<Beam> = HashSet::new();
+
+    // only copy the grid to draw on if anyone will see it
+    let mut display = trace::enabled(Level::Debug, module_path!()).then(|| grid.clone());
+    loop {
+        // Exit loop if we've exhausted all active beams
+        if active.is_empty() { break; }
+
+        // Pop the next active beam
+        let beam = active.pop_front().unwrap();
+        visited.insert(beam);
+        energised.insert(beam.loc);
+
+        // Turn the beam based on the mirror, and add new beams to the queue
+        for next in beam.turn(grid) {
+            if let Some(next) = next.mv(grid) {
+                if !visited.contains(&next) {
+                    active.push_back(next);
+                }
+            }
+        }
+
+        // Update the display
+        if let Some(display) = display.as_mut() {
+            if display[beam.loc] == '.' {
+                display[beam.loc] = beam.dir.arrow();
+            }
+        }
+    }
+    if let Some(display) = display {
+        debug!("starting from {:?}, {} energised\n{}", start, energised.len(), display);
+    }
+    energised.len()
+}
\ No newline at end of file
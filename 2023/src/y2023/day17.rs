@@ -0,0 +1,193 @@
+// SO MANY HOURS! figuring out the state and key representations for djikastra!
+use std::{collections::{BinaryHeap, HashMap}, cmp::Ordering};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::error::{AocError, Result};
+use crate::params::Param;
+use crate::debug;
+use crate::solution::Solution;
+use crate::trace::{self, Level};
+use crate::utils::{Direction, Grid, Point, Vec2};
+
+pub struct Day17 {
+    grid: Grid<u8>,
+}
+
+impl Solution for Day17 {
+    fn parse(content: &str) -> Result<Day17> {
+        // a crucible which has to go further than it can would never find a path
+        for (min_run, max_run) in [(MIN_RUN, MAX_RUN), (ULTRA_MIN_RUN, ULTRA_MAX_RUN)] {
+            if max_run.value() < min_run.value() {
+                return Err(AocError::new(format!("{} can't be less than {}", max_run.name, min_run.name)));
+            }
+        }
+        let grid = Grid::parse_with(content, |c| c.to_digit(10).map(|d| d as u8))?;
+        Ok(Day17{ grid })
+    }
+
+    fn part1(&self) -> Answer {
+        let crucible = Crucible{ min_run: MIN_RUN.value(), max_run: MAX_RUN.value() };
+        Answer::from(find_path(&self.grid, crucible))
+    }
+
+    fn part2(&self) -> Answer {
+        let crucible = Crucible{ min_run: ULTRA_MIN_RUN.value(), max_run: ULTRA_MAX_RUN.value() };
+        Answer::from(find_path(&self.grid, crucible))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![MIN_RUN, MAX_RUN, ULTRA_MIN_RUN, ULTRA_MAX_RUN]
+    }
+}
+
+
+const MIN_RUN: Param = Param::new("min_run", 1, "fewest blocks the crucible moves before turning or stopping in part 1").at_least(1);
+const MAX_RUN: Param = Param::new("max_run", 3, "most blocks the crucible moves in a straight line in part 1").at_least(1);
+const ULTRA_MIN_RUN: Param = Param::new("ultra_min_run", 4, "fewest blocks the ultra crucible moves before turning or stopping in part 2").at_least(1);
+const ULTRA_MAX_RUN: Param = Param::new("ultra_max_run", 10, "most blocks the ultra crucible moves in a straight line in part 2").at_least(1);
+
+
+/// How far a crucible has to go in one direction before it can turn, and how far it can go
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Crucible {
+    min_run: usize,
+    max_run: usize,
+}
+
+
+/// Use Djikastra to find shortest path
+fn find_path(grid: &Grid<u8>, crucible: Crucible) -> usize {
+
+    // initialize the heap
+    let mut heap = BinaryHeap::new();
+    heap.push(State::start(crucible));
+
+    // initialize the dists
+    let max_time = crucible.max_run;
+    let dirs = [None, Some(Direction::Up), Some(Direction::Right), Some(Direction::Down), Some(Direction::Left)];
+    let mut dists: HashMap<(Point, Option<Direction>, usize), usize> =
+        (0..grid.nrows)
+        .cartesian_product(0..grid.ncols)
+        .cartesian_product(dirs)
+        .cartesian_product(0..max_time + 1)
+        .map(|x| ((Point::new(x.0.0.0, x.0.0.1), x.0.1, x.1), usize::MAX))
+        .collect();
+    dists.insert(State::start(crucible).key(), 0);
+
+    // define the target
+    let target = Point::new(grid.nrows - 1, grid.ncols - 1);
+
+    while let Some(state) = heap.pop() {
+        // we've arrived at the target!
+        if (state.loc == target) && state.allowed_to_stop() {
+            if trace::enabled(Level::Debug, module_path!()) { show(grid, &state); }
+            return state.cost;
+        }
+
+        // handle new history
+        let mut prev = vec![(state.loc, state.dir)];
+        if !state.prev.is_empty() { prev.extend(state.prev.iter()); }
+
+        // add neighbors to the heap
+        for neighbor in state.neighbors(grid) {
+            let next = State::new(
+                state.cost + grid[neighbor] as usize,
+                neighbor,
+                prev.clone(),
+                crucible,
+            );
+            if next.cost < dists[&next.key()] {
+                dists.insert(next.key(), next.cost);
+                heap.push(next);
+            }
+        }
+    }
+    0
+}
+
+
+fn show(grid: &Grid<u8>, state: &State) {
+    let mut grid = grid.map(|d| char::from(b'0' + d));
+    for (p, dir) in state.prev.iter() {
+        grid[*p] = dir.map_or('.', |d| d.arrow());
+    }
+    debug!("path costing {}\n{}", state.cost, grid);
+}
+
+
+#[derive(Clone, Eq, PartialEq)]
+struct State {
+    cost: usize,
+    loc: Point,
+    prev: Vec<(Point, Option<Direction>)>,
+    /// Direction we arrived from, which is None at the start
+    dir: Option<Direction>,
+    time_in_dir: usize,
+    crucible: Crucible,
+}
+
+impl State {
+    fn new(cost: usize, loc: Point, prev: Vec<(Point, Option<Direction>)>, crucible: Crucible) -> State {
+        let (dir, time_in_dir) = if prev.is_empty() {
+            (None, 0)
+        } else {
+            let dir = Direction::from_delta(Vec2::from(loc) - Vec2::from(prev[0].0));
+            let time_in_dir = 1 + prev.iter().take_while(|(_, d)| *d == dir).count();
+            (dir, time_in_dir)
+        };
+        State{ cost, loc, prev, dir, time_in_dir, crucible }
+    }
+
+    /// Start in top left corner
+    fn start(crucible: Crucible) -> State {
+        State::new(0, Point::new(0, 0), vec![], crucible)
+    }
+
+    /// Key for position, including ability to move horizontal or vertical
+    fn key(&self) -> (Point, Option<Direction>, usize) {
+        (self.loc, self.dir, self.time_in_dir)
+    }
+
+    /// Is the crucible allowed to stop?
+    fn allowed_to_stop(&self) -> bool {
+        self.time_in_dir >= self.crucible.min_run
+    }
+
+    /// Valid neighbors - ensuring we don't go off-grid, turn too soon or go too far in one direction
+    fn neighbors(&self, grid: &Grid<u8>) -> Vec<Point> {
+        let Crucible{ min_run, max_run } = self.crucible;
+        Direction::ORTHOGONAL
+            .iter()
+            .filter(|dir| match self.dir {
+                // handle start conditions
+                None => true,
+                // don't go back on yourself!
+                Some(d) if **dir == d.opposite() => false,
+                // keep going if we haven't gone too far
+                Some(d) if **dir == d => self.time_in_dir < max_run,
+                // turn once we've gone far enough
+                Some(_) => self.time_in_dir >= min_run,
+            })
+            .filter_map(|dir| self.loc.step(*dir, grid))
+            .collect()
+    }
+}
+
+/// Ordering required for heap
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Flip the ordering on costs so it becomes a min-heap
+        other.cost.cmp(&self.cost)
+            .then_with(|| self.loc.r.cmp(&other.loc.r))
+            .then_with(|| self.loc.c.cmp(&other.loc.c))
+    }
+}
+
+// PartialOrd also required for heap
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
\ No newline at end of file
@@ -1,53 +1,67 @@
 // SO BLOODY LONG...maybe 4 hours or so, faffing with inequalities!
 use itertools::Itertools;
 
-use crate::utils::{Point, Grid};
-
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content);
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, AocError, Result};
+use crate::solution::Solution;
+use crate::trace;
+use crate::utils::{Direction, Point, Vec2};
+
+pub struct Day18 {
+    plan: DigPlan,
+    fixed_plan: DigPlan,
 }
 
+impl Solution for Day18 {
+    fn parse(content: &str) -> Result<Day18> {
+        Ok(Day18{ plan: DigPlan::new(content, false)?, fixed_plan: DigPlan::new(content, true)? })
+    }
 
-fn part1(content: &str) {
-    println!("PART 1: {}", solve(content, false));
-}
-
-
-fn part2(content: &str) {
-    println!("PART 2: {}", solve(content, true));
-}
-
+    fn part1(&self) -> Answer {
+        Answer::from(self.plan.execute())
+    }
 
-fn solve(content: &str, fixed: bool) -> usize {
-    let plan = DigPlan::new(content, fixed);
-    plan.execute()
+    fn part2(&self) -> Answer {
+        Answer::from(self.fixed_plan.execute())
+    }
 }
 
 
 #[derive(Debug, Clone, Copy)]
 struct Instruction {
-    dir: char,
+    dir: Direction,
     length: usize,
 }
 
 impl Instruction {
-    fn new(line: &str, fixed: bool) -> Instruction {
+    fn new(line: &str, fixed: bool) -> Result<Instruction> {
         let parts = line.split_whitespace().collect_vec();
+        if parts.len() != 3 {
+            return Err(AocError::invalid("instruction", line));
+        }
         if !fixed {
             // for part 1
-            Instruction{
-                dir: parts[0].chars().nth(0).unwrap(),
-                length: parts[1].parse().unwrap(),
-            }
+            let dir = Some(parts[0])
+                .filter(|d| d.len() == 1)
+                .and_then(|d| Direction::from_letter(d.chars().next()?))
+                .ok_or_else(|| AocError::invalid("direction", parts[0]))?;
+            Ok(Instruction{
+                dir,
+                length: parse_num(parts[1])?,
+            })
         } else {
             // for part 2
-            Instruction{
-                dir: match parts[2].chars().nth(7).unwrap() {
-                    '0' => 'R', '1' => 'D', '2' => 'L', '3' => 'U', _ => '.'
-                },
-                length: usize::from_str_radix(&parts[2][2..7], 16).unwrap(),
-            }
+            let color = parts[2];
+            let hex = color
+                .strip_prefix("(#")
+                .and_then(|c| c.strip_suffix(')'))
+                .filter(|c| (c.len() == 6) && c.chars().all(|c| c.is_ascii_hexdigit()))
+                .ok_or_else(|| AocError::invalid("color", color))?;
+            Ok(Instruction{
+                dir: Direction::from_digit(hex.chars().nth(5).unwrap())
+                    .ok_or_else(|| AocError::invalid("color", color))?,
+                length: usize::from_str_radix(&hex[..5], 16).unwrap(),
+            })
         }
     }
 }
@@ -61,7 +75,7 @@ struct Edge {
 
 impl Edge {
     fn new(p1: Point, p2: Point) -> Edge {
-        let mut points = vec![p1, p2];
+        let mut points = [p1, p2];
         points.sort();
         Edge{ start: points[0], stop: points[1] }
     }
@@ -99,31 +113,27 @@ impl Box {
         if self.is_inside(trench, &self.start) { size += 1; }
 
         // left edge
-        if nrows >= 2 {
-            if self.is_inside(trench, &self.start.down(1)) {
-                size += nrows - 1;
-            }
+        if (nrows >= 2) && self.is_inside(trench, &Point::new(self.start.r + 1, self.start.c)) {
+            size += nrows - 1;
         }
         // top edge
-        if ncols >= 2 {
-            if self.is_inside(trench, &self.start.right(1)) {
-                size += ncols - 1;
-            }
+        if (ncols >= 2) && self.is_inside(trench, &Point::new(self.start.r, self.start.c + 1)) {
+            size += ncols - 1;
         }
         // inside
-        if (nrows >= 2) && (ncols >= 2) {
-            if self.is_inside(trench, &self.start.down(1).right(1)) {
-                size += (nrows - 1) * (ncols - 1);
-            }
+        if (nrows >= 2) && (ncols >= 2) && self.is_inside(trench, &Point::new(self.start.r + 1, self.start.c + 1)) {
+            size += (nrows - 1) * (ncols - 1);
+        }
+        if size > 0 {
+            trace!(
+                "({} -> {}, {} -> {}) {}",
+                self.start.r + 1,
+                self.stop.r,
+                self.start.c + 1,
+                self.stop.c,
+                size,
+            );
         }
-        /*if size > 0 {
-            println!("({} -> {}, {} -> {}) {}",
-            self.start.r + 1,
-            self.stop.r,
-            self.start.c + 1,
-            self.stop.c,
-            size);
-        }*/
         size
     }
 
@@ -133,7 +143,7 @@ impl Box {
             return false;
         }
         // horizontal edges above
-        let n_crossings = trench.into_iter()
+        let n_crossings = trench.iter()
             .filter(|e| e.is_horizontal())
             .filter(|e| e.start.r < p.r)  // is above
             .filter(|e| e.start.c <= p.c)
@@ -151,18 +161,15 @@ struct DigPlan {
 }
 
 impl DigPlan {
-    fn new(content: &str, fixed: bool) -> DigPlan {
-        DigPlan{
-            instructions: content.split("\n")
-                .map(|line| Instruction::new(line, fixed))
-                .collect(),
-        }
+    fn new(content: &str, fixed: bool) -> Result<DigPlan> {
+        Ok(DigPlan{
+            instructions: parse_lines(content, |line| Instruction::new(line, fixed))?,
+        })
     }
 
     /// Execute the plan
     fn execute(&self) -> usize {
         let trench = self.dig_trench();
-        //_show(&trench);
         let boxes = self.to_boxes(&trench);
         let trench_size: usize = trench.iter().map(|e| e.length()).sum();
         let interior_size: usize = boxes.iter().map(|b| b.interior(&trench)).sum();
@@ -171,28 +178,23 @@ impl DigPlan {
     
     /// Collect a vector of edges
     fn dig_trench(&self) -> Vec<Edge> {
-        let mid = 100_000_000;
-        let mut loc = Point::new(mid, mid);
-        let mut trench = vec![];
+        let mut loc = Vec2::new(0, 0);
+        let mut corners = vec![loc];
         for i in self.instructions.iter() {
-            let next = match i.dir {
-                'U' => { loc.up(i.length) },
-                'D' => { loc.down(i.length) },
-                'L' => { loc.left(i.length) },
-                'R' => { loc.right(i.length) },
-                _ => loc,
-            };
-            trench.push(Edge::new(loc, next));
-            loc = next;
+            loc += i.dir.delta() * i.length as i64;
+            corners.push(loc);
         }
-        // make nicer coords
-        let minr = trench.iter().map(|e| e.start.r).min().unwrap();
-        let minc = trench.iter().map(|e| e.start.c).min().unwrap();
-        trench.iter()
-            .map(|e| Edge::new(
-                Point::new(e.start.r - minr, e.start.c - minc),
-                Point::new(e.stop.r - minr, e.stop.c - minc),
-            ))
+        // shift everything so the top left is at the origin
+        let min = Vec2::new(
+            corners.iter().map(|p| p.r).min().unwrap(),
+            corners.iter().map(|p| p.c).min().unwrap(),
+        );
+        let corners = corners.iter()
+            .map(|p| Point::try_from(*p - min).unwrap())
+            .collect_vec();
+        corners.iter()
+            .tuple_windows()
+            .map(|(p1, p2)| Edge::new(*p1, *p2))
             .collect_vec()
     }
 
@@ -213,31 +215,12 @@ impl DigPlan {
             .collect();
         (1..rows.len())
             .cartesian_product(1..cols.len())
-            .map(|x| (
+            .map(|x|
                 Box::new(
                     Point::new(rows[x.0 - 1], cols[x.1 - 1]),
                     Point::new(rows[x.0], cols[x.1])
                 )
-            ))
+            )
             .collect()
     }
-}
-
-
-fn _show(trench: &[Edge]) {
-    let maxr = trench.iter().map(|e| e.stop.r).max().unwrap();
-    let maxc = trench.iter().map(|e| e.stop.c).max().unwrap();
-    let cells = (0..maxr + 1)
-        .map(
-            |r| (0..maxc + 1)
-            .map(
-                |c|
-                if trench.iter()
-                    .any(|e| e.contains(&Point::new(r, c)))
-                    { '#' } else { '.' }
-            )
-            .collect_vec()
-        )
-        .collect_vec();
-    println!("{}", Grid::new(cells).to_string());
 }
\ No newline at end of file
@@ -1,35 +1,48 @@
 // 45 mins for part 1, maybe 1.5 hours for part 2
-use std::collections::{HashMap, VecDeque};
+use std::{collections::{HashMap, VecDeque}, fmt::Display};
 
 use itertools::Itertools;
 
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content, false);
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, sections, split_pair, AocError, Result};
+use crate::debug;
+use crate::solution::Solution;
+
+pub struct Day19 {
+    workflows: HashMap<String, Workflow>,
+    parts: Vec<Part>,
 }
 
+impl Solution for Day19 {
+    fn parse(content: &str) -> Result<Day19> {
+        let (workflows, parts) = load_workflows_and_parts(content)?;
+        Ok(Day19{ workflows, parts })
+    }
 
-fn part1(content: &str) {
-    let (workflows, parts) = load_workflows_and_parts(content);
-    let mut accepted = 0_usize;
-    for part in parts {
-        let mut name = "in";
-        loop {
-            let next = workflows[name].apply(&part);
-            match next {
-                "A" => { accepted += part.value(); break },
-                "R" => break,
-                _ => { name = next },
+    fn part1(&self) -> Answer {
+        let workflows = &self.workflows;
+        let mut accepted = 0_usize;
+        for part in self.parts.iter() {
+            let mut name = "in";
+            loop {
+                let next = workflows[name].apply(part);
+                match next {
+                    "A" => { accepted += part.value(); break },
+                    "R" => break,
+                    _ => { name = next },
+                }
             }
         }
+        Answer::from(accepted)
     }
-    println!("PART 1: {}", accepted);
-}
 
+    fn part2(&self) -> Answer {
+        Answer::from(count_combinations(&self.workflows))
+    }
+}
 
-fn part2(content: &str, verbose: bool) {
-    let (workflows, _) = load_workflows_and_parts(content);
 
+fn count_combinations(workflows: &HashMap<String, Workflow>) -> usize {
     // Instantiate the queue
     let start = PartRange::new((1, 4000), (1, 4000), (1, 4000), (1, 4000));
     let mut queue: VecDeque<(_, &str)> = VecDeque::new();
@@ -37,19 +50,19 @@ fn part2(content: &str, verbose: bool) {
 
     let mut n_combinations = 0_usize;
     while let Some((range, name)) = queue.pop_front() {
-        if verbose { println!("{}\t{}", name, range.to_string()); }
+        debug!("{}\t{}", name, range);
         match name {
             "A" => { n_combinations += range.n_combinations() },
             "R" => (),
             _ => {
                 for result in workflows[name].apply_to_range(&range) {
-                    if verbose { println!(" -> {}\t{}", result.1, result.0.to_string()); }
+                    debug!(" -> {}\t{}", result.1, result.0);
                     queue.push_back(result);
                 }
             },
         }
     }
-    println!("PART 2: {}", n_combinations);
+    n_combinations
 }
 
 
@@ -61,15 +74,16 @@ struct Workflow {
 
 impl Workflow {
     /// Instantiate from input line
-    fn from_string(line: &str) -> Workflow {
-        let name = line.split("{").next().unwrap();
-        Workflow {
+    fn from_string(line: &str) -> Result<Workflow> {
+        let (name, rules) = split_pair(line, "{")?;
+        let rules = rules.strip_suffix('}').ok_or_else(|| AocError::invalid("workflow", line))?;
+        Ok(Workflow {
             name: String::from(name),
-            rules: line[name.len() + 1..line.len() - 1]
+            rules: rules
                 .split(",")
-                .map(|r| Rule::from_string(r))
-                .collect(),
-        }
+                .map(Rule::from_string)
+                .collect::<Result<_>>()?,
+        })
     }
 
     /// Apply the workflow to a single part
@@ -88,7 +102,7 @@ impl Workflow {
         for rule in self.rules.iter() {
             let mut next: Vec<(_, &str)> = vec![];
             for (range, attr) in ranges.iter() {
-                if *attr == "" {
+                if attr.is_empty() {
                     let (passed, failed) = rule.apply_to_range(range);
                     if let Some(passed) = passed { next.push((passed, &rule.destination)); }
                     if let Some(failed) = failed { next.push((failed, "")); }
@@ -113,22 +127,27 @@ struct Rule {
 
 impl Rule {
     /// Instantiate from input string
-    fn from_string(part: &str) -> Rule {
+    fn from_string(part: &str) -> Result<Rule> {
         let bits = part.split(":").collect_vec();
         if bits.len() == 1 {
-            Rule{
+            Ok(Rule{
                 destination: String::from(bits[0]),
                 category: '.',
                 condition: '.',
                 value: 0,
-            }
+            })
         } else {
-            Rule{
+            let category = bits[0].chars().nth(0).filter(|c| "xmas".contains(*c));
+            let condition = bits[0].chars().nth(1).filter(|c| "<>".contains(*c));
+            let (Some(category), Some(condition), 2) = (category, condition, bits.len()) else {
+                return Err(AocError::invalid("rule", part));
+            };
+            Ok(Rule{
                 destination: String::from(bits[1]),
-                category: bits[0].chars().nth(0).unwrap(),
-                condition: bits[0].chars().nth(1).unwrap(),
-                value: bits[0][2..].parse().unwrap(),
-            }
+                category,
+                condition,
+                value: parse_num(&bits[0][2..])?,
+            })
         }
     }
 
@@ -174,6 +193,26 @@ impl Part {
         Part{ x, m, a, s }
     }
 
+    /// Instantiate from input line e.g. "{x=787,m=2655,a=1222,s=2876}"
+    fn from_string(line: &str) -> Result<Part> {
+        let ratings = line
+            .strip_prefix('{')
+            .and_then(|l| l.strip_suffix('}'))
+            .ok_or_else(|| AocError::invalid("part", line))?;
+        let vals: Vec<usize> = ratings
+            .split(",")
+            .zip(["x=", "m=", "a=", "s="])
+            .map(|(rating, prefix)| match rating.strip_prefix(prefix) {
+                Some(value) => parse_num(value),
+                None => Err(AocError::invalid("rating", rating)),
+            })
+            .collect::<Result<_>>()?;
+        if vals.len() != 4 {
+            return Err(AocError::invalid("part", line));
+        }
+        Ok(Part::new(vals[0], vals[1], vals[2], vals[3]))
+    }
+
     /// Value is the sum of ratings
     fn value(&self) -> usize {
         self.x + self.m + self.a + self.s
@@ -224,7 +263,7 @@ impl PartRange {
         if (value <= low) || (value > high) {
             vec![*self]
         } else {
-            let (mut lower, mut upper) = (self.clone(), self.clone());
+            let (mut lower, mut upper) = (*self, *self);
             match attr {
                 'x' => { lower.x = (self.x.0, value - 1); upper.x = (value, self.x.1) },
                 'm' => { lower.m = (self.m.0, value - 1); upper.m = (value, self.m.1) },
@@ -235,9 +274,12 @@ impl PartRange {
             vec![lower, upper]
         }
     }
+}
 
-    fn to_string(&self) -> String {
-        format!(
+impl Display for PartRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
             "[x({}, {}) m({} {}) a({} {}) s({} {})]",
             self.x.0, self.x.1,
             self.m.0, self.m.1,
@@ -248,25 +290,27 @@ impl PartRange {
 }
 
 
-fn load_workflows_and_parts(content: &str) -> (HashMap<String, Workflow>, Vec<Part>) {
-    let mut workflows = HashMap::new();
-    let mut parts = vec![];
-    let mut passed_cutoff = false;
-    for line in content.split("\n") {
-        if line.is_empty() { 
-            passed_cutoff = true;
-            continue;
-        }
-        if !passed_cutoff {
-            let workflow = Workflow::from_string(line);
-            workflows.insert(workflow.name.clone(), workflow);
-        } else {
-            let vals: Vec<usize> = line[1..line.len() - 1]
-                .split(",")
-                .map(|x| x[2..].parse().unwrap())
-                .collect_vec();
-            parts.push(Part::new(vals[0], vals[1], vals[2], vals[3]));
+fn load_workflows_and_parts(content: &str) -> Result<(HashMap<String, Workflow>, Vec<Part>)> {
+    let sections = sections(content);
+    if sections.len() != 2 {
+        return Err(AocError::new("expected workflows, a blank line, then parts"));
+    }
+    let (start, section) = &sections[0];
+    let workflows: HashMap<String, Workflow> = parse_lines(section, Workflow::from_string)
+        .map_err(|e| e.below(*start))?
+        .into_iter()
+        .map(|w| (w.name.clone(), w))
+        .collect();
+    if !workflows.contains_key("in") {
+        return Err(AocError::new("no \"in\" workflow"));
+    }
+    for rule in workflows.values().flat_map(|w| w.rules.iter()) {
+        let dest = rule.destination.as_str();
+        if (dest != "A") && (dest != "R") && !workflows.contains_key(dest) {
+            return Err(AocError::new(format!("unknown workflow {:?}", dest)));
         }
     }
-    (workflows, parts)
+    let (start, section) = &sections[1];
+    let parts = parse_lines(section, Part::from_string).map_err(|e| e.below(*start))?;
+    Ok((workflows, parts))
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+// 26 mins
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, split_pair, AocError, Result};
+use crate::solution::Solution;
+
+pub struct Day2 {
+    games: Vec<Game>,
+}
+
+impl Solution for Day2 {
+    fn parse(content: &str) -> Result<Day2> {
+        Ok(Day2{ games: parse_lines(content, Game::from_string)? })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut id_sum = 0;
+        for game in self.games.iter() {
+            let mut success = true;
+            for set in game.sets.iter() {
+                for (count, color) in set {
+                    let (count, color) = (*count, color.as_str());
+                    if ((color == "red") & (count > 12)) |
+                        ((color == "green") & (count > 13)) |
+                        ((color == "blue") & (count > 14)) {
+                        success = false;
+                        break;
+                    }
+                }
+            }
+            if success {
+                id_sum += game.id;
+            }
+        }
+        Answer::from(id_sum)
+    }
+
+    fn part2(&self) -> Answer {
+        let mut power_sum = 0;
+        for game in self.games.iter() {
+            let mut n_red = 0;
+            let mut n_green = 0;
+            let mut n_blue = 0;
+            for set in game.sets.iter() {
+                for (count, color) in set {
+                    let (count, color) = (*count, color.as_str());
+                    if (color == "red") & (count > n_red) { n_red = count };
+                    if (color == "green") & (count > n_green) { n_green = count };
+                    if (color == "blue") & (count > n_blue) { n_blue = count };
+                }
+            }
+            power_sum += n_red * n_green * n_blue;
+        }
+        Answer::from(power_sum)
+    }
+}
+
+
+/// A game is an id, and the sets of (count, color) balls revealed
+struct Game {
+    id: u32,
+    sets: Vec<Vec<(i32, String)>>,
+}
+
+impl Game {
+    fn from_string(line: &str) -> Result<Game> {
+        let (name, sets) = split_pair(line.trim(), ": ")?;
+        let id = name.strip_prefix("Game ").ok_or_else(|| AocError::invalid("game", name))?;
+        let sets = sets.split("; ")
+            .map(|set| set.split(", ").map(to_counts).collect::<Result<Vec<_>>>())
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Game{ id: parse_num(id)?, sets })
+    }
+}
+
+
+fn to_counts(count_str: &str) -> Result<(i32, String)> {
+    let (count, color) = split_pair(count_str, " ")?;
+    if !["red", "green", "blue"].contains(&color) {
+        return Err(AocError::invalid("color", color));
+    }
+    Ok((parse_num(count)?, String::from(color)))
+}
\ No newline at end of file
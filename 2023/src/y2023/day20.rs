@@ -0,0 +1,343 @@
+// 2 hours for part 1 - maybe another hour for part 2
+
+use std::{collections::{HashMap, VecDeque}, fmt::{Debug, Display}};
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, split_pair, AocError, Result};
+use crate::params::Param;
+use crate::{debug, trace};
+use crate::solution::{Check, Solution};
+
+pub struct Day20 {
+    modules: HashMap<String, Box<dyn Module>>,
+}
+
+impl Solution for Day20 {
+    fn parse(content: &str) -> Result<Day20> {
+        Ok(Day20{ modules: parse_content(content)? })
+    }
+
+    fn part1(&self) -> Answer {
+        Answer::from(count_pulses(self.modules.clone()))
+    }
+
+    /// The node "rx" gets a low signal when all inputs to conjunction "vr" are low
+    /// The four inputs to "vr" are "bm", "cl", "tn", "dr" - so assume they operate
+    /// on some fixed (prime-number) cycle
+    fn part2(&self) -> Answer {
+        // relies on the structure of the real input, so there's no answer for the examples
+        if !self.modules.contains_key("vr") { return Answer::Unsolved; }
+        let mut modules = self.modules.clone();
+        let mut cycle_lengths = HashMap::new();
+        for node in ["bm", "cl", "tn", "dr"] {
+            cycle_lengths.insert(String::from(node), usize::MAX);
+        }
+        let mut n_presses = 0_usize;
+        loop {
+            n_presses += 1;
+            let mut queue = VecDeque::new();
+            queue.push_back((String::from("button"), String::from("broadcaster"), Pulse::Low));
+            while let Some((source, dest, pulse)) = queue.pop_front() {
+                if let Some(module) = modules.get_mut(&dest) {
+                    if dest == "vr" {
+                        module.update_cycle_lengths(n_presses, &mut cycle_lengths);
+                        if cycle_lengths.values().all(|v| *v < usize::MAX) {
+                            return Answer::from(cycle_lengths.values().product::<usize>());
+                        }
+                    }
+                    for (next, pulse) in module.pulse(&source, pulse) {
+                        queue.push_back((dest.clone(), next, pulse));
+                    }
+                }
+            }
+        }
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![PRESSES]
+    }
+
+    /// Part 2 watches the inputs to "vr" by name, as the only thing sending to "rx"
+    fn checks(&self) -> Vec<Check> {
+        let feeders = self.modules.values()
+            .filter(|m| m.dests().iter().any(|d| d == "rx"))
+            .map(|m| m.name())
+            .sorted()
+            .collect_vec();
+        let inputs = self.modules.get("vr")
+            .and_then(|m| m.inputs())
+            .map(|inputs| inputs.into_iter().sorted().collect_vec());
+        vec![
+            Check::new("rx is fed by a single conjunction vr", (feeders == ["vr"]) && inputs.is_some(), || {
+                if feeders == ["vr"] {
+                    String::from("vr isn't a conjunction")
+                } else {
+                    format!("rx is fed by [{}]", feeders.join(", "))
+                }
+            }),
+            Check::new("vr is fed by bm, cl, dr and tn", inputs.as_ref().is_some_and(|i| *i == ["bm", "cl", "dr", "tn"]), || {
+                format!("vr is fed by [{}]", inputs.as_deref().unwrap_or_default().join(", "))
+            }),
+        ]
+    }
+}
+
+
+const PRESSES: Param = Param::new("presses", 1000, "times to press the button in part 1");
+
+
+fn count_pulses(mut modules: HashMap<String, Box<dyn Module>>) -> usize {
+    let mut nlow = 0_usize;
+    let mut nhigh = 0_usize;
+    for i in 0..PRESSES.value() {
+        debug!("button press {}", i + 1);
+        let mut queue = VecDeque::new();
+        queue.push_back((String::from("button"), String::from("broadcaster"), Pulse::Low));
+        while let Some((source, dest, pulse)) = queue.pop_front() {
+            trace!("{} -{}-> {}", &source, pulse, &dest);
+            match pulse {
+                Pulse::Low => { nlow += 1 },
+                Pulse::High => { nhigh += 1 },
+            }
+            if let Some(module) = modules.get_mut(&dest) {
+                for (next, pulse) in module.pulse(&source, pulse) {
+                    queue.push_back((dest.clone(), next, pulse));
+                }
+            }
+        }
+    }
+    nlow * nhigh
+}
+
+
+/// Definition of a pulse
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Pulse { Low, High }
+
+impl Display for Pulse {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", if *self == Pulse::Low { "low" } else { "high" })
+    }
+}
+
+
+/// Definition of a state
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State { On, Off }
+
+impl Display for State {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", if *self == State::On { "on" } else { "high" })
+    }
+}
+
+
+/// Common functionality for a module
+trait Module {
+    fn name(&self) -> String;
+    fn dests(&self) -> &[String];
+    /// Modules sending to this one, if it remembers them
+    fn inputs(&self) -> Option<Vec<String>> { None }
+    fn pulse(&mut self, from: &str, pulse: Pulse) -> Vec<(String, Pulse)>;
+    fn to_string(&self) -> String;
+    fn update_cycle_lengths(&self, _: usize, _: &mut HashMap<String, usize>) {}
+    fn box_clone(&self) -> Box<dyn Module>;
+
+    fn send(&self, dests: &[String], pulse: Pulse) -> Vec<(String, Pulse)> {
+        dests.iter().map(|d| (d.clone(), pulse)).collect()
+    }
+}
+
+impl Debug for dyn Module {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.to_string())
+    }
+}
+
+impl Clone for Box<dyn Module> {
+    fn clone(&self) -> Box<dyn Module> {
+        self.box_clone()
+    }
+}
+
+
+/// The broadcast module
+#[derive(Debug, Clone)]
+struct Broadcaster {
+    name: String,
+    dests: Vec<String>
+}
+
+impl Broadcaster {
+    fn new(name: &str, dests: Vec<String>) -> Broadcaster {
+        Broadcaster{ name: String::from(name), dests }
+    }
+}
+
+impl Module for Broadcaster {
+    fn name(&self) -> String { self.name.clone() }
+    fn dests(&self) -> &[String] { &self.dests }
+    fn box_clone(&self) -> Box<dyn Module> { Box::new(self.clone()) }
+
+    /// Pass the pulse on to the destinations
+    fn pulse(&mut self, _from: &str, pulse: Pulse) -> Vec<(String, Pulse)> {
+        self.send(&self.dests, pulse)
+    }
+
+    fn to_string(&self) -> String {
+        format!("{}->[{}]", self.name(), self.dests.join(","))
+    }
+}
+
+
+#[derive(Debug, Clone)]
+struct FlipFlop {
+    name: String,
+    dests: Vec<String>,
+    state: State,
+}
+
+impl FlipFlop {
+    fn new(name: &str, dests: Vec<String>) -> FlipFlop {
+        FlipFlop{ name: String::from(name), dests, state: State::Off }
+    }
+}
+
+impl Module for FlipFlop {
+    fn name(&self) -> String { self.name.clone() }
+    fn dests(&self) -> &[String] { &self.dests }
+    fn box_clone(&self) -> Box<dyn Module> { Box::new(self.clone()) }
+
+    /// Flip flop if input pulse is low
+    fn pulse(&mut self, _from: &str, pulse: Pulse) -> Vec<(String, Pulse)> {
+        match (pulse, self.state) {
+            (Pulse::Low, State::Off) => {
+                self.state = State::On;
+                self.send(&self.dests, Pulse::High)
+            },
+            (Pulse::Low, State::On) => {
+                self.state = State::Off;
+                self.send(&self.dests, Pulse::Low)
+            },
+            _ => { vec![] },
+        }
+    }
+
+    fn to_string(&self) -> String {
+        format!(
+            "{}->[{}]({})",
+            self.name(),
+            self.dests.join(","),
+            self.state,
+        )
+    }
+}
+
+
+/// The conjunction module
+#[derive(Debug, Clone)]
+struct Conjunction {
+    name: String,
+    dests: Vec<String>,
+    memory: HashMap<String, Pulse>,
+}
+
+impl Conjunction {
+    fn new(name: &str, dests: Vec<String>, inputs: &[String]) -> Conjunction {
+        Conjunction{
+            name: String::from(name),
+            dests,
+            memory: HashMap::from_iter(inputs.iter().map(|i| (i.clone(), Pulse::Low))),
+        }
+    }
+}
+
+impl Module for Conjunction {
+    fn name(&self) -> String { self.name.clone() }
+    fn dests(&self) -> &[String] { &self.dests }
+    fn box_clone(&self) -> Box<dyn Module> { Box::new(self.clone()) }
+
+    /// Flip flop if input pulse is low
+    fn pulse(&mut self, from: &str, pulse: Pulse) -> Vec<(String, Pulse)> {
+        self.memory.insert(String::from(from), pulse);
+        let all_high = self.memory.values().all(|v| *v == Pulse::High);
+        self.send(
+            &self.dests,
+            if all_high { Pulse::Low } else { Pulse::High },
+        )
+    }
+
+    fn to_string(&self) -> String {
+        let mem = self.memory
+            .iter()
+            .map(|x| format!("{}:{}", x.0, x.1))
+            .join(",");
+        format!("{}->[{}]({})", self.name(), self.dests.join(","), mem)
+    }
+
+    fn inputs(&self) -> Option<Vec<String>> {
+        Some(self.memory.keys().cloned().collect())
+    }
+
+    /// Hacky solution for part 2
+    fn update_cycle_lengths(&self, n_presses: usize, cycle_lengths: &mut HashMap<String, usize>) {
+        for node in self.memory
+            .iter()
+            .filter(|kv| *kv.1 == Pulse::High)
+            .map(|kv| kv.0) {
+            if n_presses < cycle_lengths[node] {
+                cycle_lengths.insert(node.clone(), n_presses);
+            }
+        }
+    }
+}
+
+
+fn parse_content(content: &str) -> Result<HashMap<String, Box<dyn Module>>> {
+    let lines = parse_lines(content, parse_line)?;
+    if !lines.iter().any(|(name, _)| name == "broadcaster") {
+        return Err(AocError::new("no broadcaster module"));
+    }
+    let mut inputs = HashMap::new();
+    for (source, dests) in lines.iter().cloned() {
+        for dest in dests {
+            inputs.entry(dest)
+                .and_modify(|v: &mut Vec<String>| v.push(source.clone()))
+                .or_insert(vec![source.clone()]);
+        }
+    }
+    let mut modules: HashMap<String, Box<dyn Module>> = HashMap::new();
+    for (line, (name, dests)) in content.split("\n").zip(lines) {
+        if line.starts_with('%') {
+            let module = FlipFlop::new(&name, dests);
+            modules.insert(module.name(), Box::new(module));
+        } else if line.starts_with('&') {
+            let module = Conjunction::new(&name, dests, inputs.get(&name).map_or(&[], |v| v));
+            modules.insert(module.name(), Box::new(module));
+        } else {
+            let module = Broadcaster::new(&name, dests);
+            modules.insert(module.name(), Box::new(module));
+        }
+    }
+    Ok(modules)
+}
+
+
+fn parse_line(line: &str) -> Result<(String, Vec<String>)> {
+    let (name, dests) = split_pair(line, " -> ")?;
+    let mut name = String::from(name);
+    if name.starts_with('%') || name.starts_with('&') {
+        name = String::from(&name[1..]);
+    } else if name != "broadcaster" {
+        return Err(AocError::invalid("module", &name));
+    }
+    Ok((
+        name,
+        dests
+            .split(", ")
+            .map(String::from)
+            .collect()
+    ))
+}
\ No newline at end of file
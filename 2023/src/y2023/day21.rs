@@ -0,0 +1,193 @@
+// 10 mins for part 1 - BLOODY HOURS for part 2!
+
+use std::collections::{HashSet, HashMap};
+
+use crate::answer::Answer;
+use crate::cancel::cancelled;
+use crate::error::Result;
+use crate::params::Param;
+use crate::solution::{Check, Solution};
+use crate::utils::{Grid, Neighborhood, Point, Vec2};
+
+pub struct Day21 {
+    grid: Grid,
+}
+
+impl Solution for Day21 {
+    fn parse(content: &str) -> Result<Day21> {
+        let grid = Grid::from_string(content)?;
+        grid.check_chars(".#S")?;
+        Ok(Day21{ grid })
+    }
+
+    fn part1(&self) -> Answer {
+        let start = find_start(&self.grid);
+        let nsteps = STEPS.value();
+        let Some(growth) = brute(&self.grid, &start, nsteps) else { return Answer::Unsolved };
+        Answer::from(get_state(&growth, nsteps))
+    }
+
+    fn part2(&self) -> Answer {
+        let grid = &self.grid;
+        let nsteps = INFINITE_STEPS.value();
+        if nsteps <= MAX_WALK {
+            // few enough steps to just walk, which works for any garden e.g. the example
+            return brute_tiled(grid, &find_start(grid), nsteps).map_or(Answer::Unsolved, Answer::from);
+        }
+        let Some(evos) = evolutions(grid) else { return Answer::Unsolved };
+
+        // 1. Handle initial block
+        let mut count = get_state(&evos["."], nsteps);
+        let width = grid.nrows - 1;
+
+        // 2. Handle moving in perpendicular directions
+        // It takes 66 steps to enter the first adjacent block to the middle one
+        if nsteps > width / 2 {
+            for dir in ["n", "s", "e", "w"] {
+                let mut n = nsteps - (width / 2 + 1);
+                loop {
+                    count += get_state(&evos[dir], n);
+                    if n < width + 1 { break; }
+                    n -= width + 1;
+                }
+            }
+        }
+
+        // 3. Handle moving in diagonal directions
+        // It takes 132 steps to enter the first diagonal block to the middle one
+        if nsteps >= width + 2 {
+            for dir in ["nw", "ne", "se", "sw"] {
+                let mut n = nsteps - (width + 2);
+                let mut i = 1;  // linearly increasing number of blocks along the edge
+                loop {
+                    count += i * get_state(&evos[dir], n);
+                    if n < width + 1 { break; }
+                    n -= width + 1;
+                    i += 1;
+                }
+            }
+        }
+        Answer::from(count)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![STEPS, INFINITE_STEPS]
+    }
+
+    /// Part 2 works out how the steps spread through copies of the grid, starting from the
+    /// middle of each edge and corner, which only works if the grid is square with the start in
+    /// the middle and a clear run along the middle row and column
+    fn checks(&self) -> Vec<Check> {
+        let grid = &self.grid;
+        let middle = find_start(grid);
+        let starts = (0..grid.nrows)
+            .flat_map(|r| (0..grid.ncols).map(move |c| Point::new(r, c)))
+            .filter(|p| *grid.get(p) == 'S')
+            .collect::<Vec<_>>();
+        let blocked_row = grid.row(middle.r).iter().position(|&c| c == '#');
+        let blocked_col = grid.col(middle.c).position(|&c| c == '#');
+        vec![
+            Check::new("grid is square with an odd size", (grid.nrows == grid.ncols) && (grid.nrows % 2 == 1), || {
+                format!("grid is {} rows by {} columns", grid.nrows, grid.ncols)
+            }),
+            Check::new("start is in the middle", starts == [middle], || {
+                format!("expected one S at {}, found {:?}", middle, starts.iter().map(|p| p.to_string()).collect::<Vec<_>>())
+            }),
+            Check::new("middle row is clear", blocked_row.is_none(), || {
+                format!("rock at {}", Point::new(middle.r, blocked_row.unwrap_or(0)))
+            }),
+            Check::new("middle column is clear", blocked_col.is_none(), || {
+                format!("rock at {}", Point::new(blocked_col.unwrap_or(0), middle.c))
+            }),
+        ]
+    }
+}
+
+
+const STEPS: Param = Param::new("steps", 64, "steps to take in part 1");
+const INFINITE_STEPS: Param = Param::new("infinite_steps", 26_501_365, "steps to take through the infinite garden in part 2");
+
+/// Most steps through the infinite garden to work out by walking them rather than part 2's shortcut
+const MAX_WALK: usize = 1000;
+
+
+/// Just use the middle
+fn find_start(grid: &Grid) -> Point {
+    Point::new((grid.nrows - 1) / 2, (grid.ncols - 1) / 2)
+}
+
+
+/// Brute force solver for a given start point, which gives up if cancelled
+fn brute(grid: &Grid, start: &Point, nsteps: usize) -> Option<Vec<usize>> {
+    let mut reachable = HashSet::new();
+    let mut growth = vec![1];
+    reachable.insert(*start);
+    for _ in 1..=nsteps {
+        if cancelled() { return None; }
+        let mut next = HashSet::new();
+        for garden in reachable {
+            next.extend(
+                grid.neighbors(&garden, Neighborhood::Orthogonal)
+                .filter(|(_, cell)| **cell != '#')
+                .map(|(nbr, _)| nbr)
+            );
+        }
+        growth.push(next.len());
+        if growth.len() > 2 && next.len() == growth[growth.len() - 3] {
+            break;
+        }
+        reachable = next;
+    }
+    Some(growth)
+}
+
+
+/// Brute force solver through copies of the garden tiled forever in every direction. A garden
+/// reached in n steps can be reached again in n + 2 by stepping away and back, so count the
+/// gardens first reached in an even (or odd) number of steps to match nsteps.
+fn brute_tiled(grid: &Grid, start: &Point, nsteps: usize) -> Option<usize> {
+    let mut seen = HashSet::from([Vec2::from(*start)]);
+    let mut frontier = vec![Vec2::from(*start)];
+    let mut count = 1 - nsteps % 2;  // the start, if nsteps is even
+    for step in 1..=nsteps {
+        if cancelled() { return None; }
+        frontier = frontier.iter()
+            .flat_map(|garden| garden.neighbors(Neighborhood::Orthogonal))
+            .filter(|nbr| *grid.tiled(nbr) != '#')
+            .filter(|nbr| seen.insert(*nbr))
+            .collect();
+        if step % 2 == nsteps % 2 {
+            count += frontier.len();
+        }
+    }
+    Some(count)
+}
+
+
+/// Mapping from starting point to how things would evolve
+fn evolutions(grid: &Grid) -> Option<HashMap<String, Vec<usize>>> {
+    let e = grid.nrows - 1;
+    let m = e / 2;
+    [
+        ("nw", 0, 0), ("n", 0, m), ("ne", 0, e),  // top
+        ("w", m, 0), (".", m, m), ("e", m, e),  // middle
+        ("sw", e, 0), ("s", e, m), ("se", e, e),  // bottom
+    ]
+    .into_iter()
+    .map(|(dir, r, c)| brute(grid, &Point::new(r, c), 300).map(|growth| (String::from(dir), growth)))
+    .collect()
+}
+
+
+/// Get the end-state, knowing that we end up flip-flopping
+fn get_state(evolution: &[usize], step: usize) -> usize {
+    let n = evolution.len();
+    if step < n {
+        evolution[step]
+    } else {
+        // e.g. step=n => rem=0 => evolution[-2]
+        // or step=n+1 => rem=1 => evolution[-1]
+        let rem = (step - n) % 2;
+        evolution[n - 2 + rem]
+    }
+}
\ No newline at end of file
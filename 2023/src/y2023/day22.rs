@@ -4,47 +4,53 @@ use std::{fmt::Debug, collections::{HashSet, VecDeque, HashMap}};
 
 use itertools::Itertools;
 
+use crate::answer::Answer;
+use crate::cancel::cancelled;
+use crate::error::{parse_lines, parse_num, split_pair, AocError, Result};
+use crate::solution::Solution;
 
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content);
+pub struct Day22 {
+    bricks: Vec<Brick>,
 }
 
+impl Solution for Day22 {
+    fn parse(content: &str) -> Result<Day22> {
+        Ok(Day22{ bricks: load_bricks(content)? })
+    }
 
-fn part1(content: &str) {
-    let bricks = load_bricks(content);
-    let bricks = find_resting_positions(&bricks);
-    // count bricks safe to distintegrate i.e. which aren't the only support for another
-    let support_map = calc_support_map(&bricks);
-    let count = bricks.iter()
-        .filter(|b| is_safe_to_disintegrate(&support_map, b))
-        .count();
-    println!("PART 1: {}", count);
-}
-
+    fn part1(&self) -> Answer {
+        let Some(bricks) = find_resting_positions(&self.bricks) else { return Answer::Unsolved };
+        // count bricks safe to distintegrate i.e. which aren't the only support for another
+        let support_map = calc_support_map(&bricks);
+        let count = bricks.iter()
+            .filter(|b| is_safe_to_disintegrate(&support_map, b))
+            .count();
+        Answer::from(count)
+    }
 
-fn part2(content: &str) {
-    let bricks = load_bricks(content);
-    let bricks = find_resting_positions(&bricks);
-    let mut count = 0;
-    for brick in bricks.iter() {
-        let mut support_map = calc_support_map(&bricks);
-        support_map = remove_bricks(&support_map, &[brick]);
-        loop {
-            // find unsupported bricks
-            let unsupported = support_map
-                .iter()
-                .filter(|(k, v)| v.is_empty() && !k.on_ground())
-                .map(|(k, _)| k)
-                .collect_vec();
-            // if no unsupported we're all good
-            if unsupported.is_empty() { break; }
-            // otherwise, remove the unsupported bricks and see what happens
-            count += unsupported.len();
-            support_map = remove_bricks(&support_map, &unsupported);
+    fn part2(&self) -> Answer {
+        let Some(bricks) = find_resting_positions(&self.bricks) else { return Answer::Unsolved };
+        let mut count = 0;
+        for brick in bricks.iter() {
+            if cancelled() { return Answer::Unsolved; }
+            let mut support_map = calc_support_map(&bricks);
+            support_map = remove_bricks(&support_map, &[brick]);
+            loop {
+                // find unsupported bricks
+                let unsupported = support_map
+                    .iter()
+                    .filter(|(k, v)| v.is_empty() && !k.on_ground())
+                    .map(|(k, _)| k)
+                    .collect_vec();
+                // if no unsupported we're all good
+                if unsupported.is_empty() { break; }
+                // otherwise, remove the unsupported bricks and see what happens
+                count += unsupported.len();
+                support_map = remove_bricks(&support_map, &unsupported);
+            }
         }
+        Answer::from(count)
     }
-    println!("PART 2: {}", count);
 }
 
 
@@ -95,21 +101,27 @@ impl Brick {
     }
 
     /// Utility to instantiate brick
-    fn from_string(line: &str) -> Brick {
-        let parts = line.split("~").collect_vec();
-        let args = parts[0]
+    fn from_string(line: &str) -> Result<Brick> {
+        let (start, end) = split_pair(line, "~")?;
+        let args = start
             .split(",")
-            .zip(parts[1].split(","))
+            .zip(end.split(","))
             .map(|pair|
-                (pair.0.parse().unwrap(), pair.1.parse().unwrap())
+                Ok((parse_num(pair.0)?, parse_num(pair.1)?))
             )
-            .collect_vec();
-        Brick{ x: args[0], y: args[1], z: args[2] }
+            .collect::<Result<Vec<(u64, u64)>>>()?;
+        if (args.len() != 3) || args.iter().any(|(lo, hi)| lo > hi) || (args[2].0 == 0) {
+            return Err(AocError::invalid("brick", line));
+        }
+        Ok(Brick{ x: args[0], y: args[1], z: args[2] })
     }
+}
 
-    /// Pretty representation
-    fn to_string(&self) -> String {
-        format!(
+/// Pretty representation
+impl Debug for Brick {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
             "[x({}-{}), y({}-{}), z({}-{})]",
             self.x.0, self.x.1,
             self.y.0, self.y.1,
@@ -118,15 +130,9 @@ impl Brick {
     }
 }
 
-impl Debug for Brick {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}", self.to_string())
-    }
-}
-
 
-/// Find resting positions of all the bricks
-fn find_resting_positions(bricks: &[Brick]) -> Vec<Brick> {
+/// Find resting positions of all the bricks, giving up if cancelled
+fn find_resting_positions(bricks: &[Brick]) -> Option<Vec<Brick>> {
     let mut at_rest: Vec<Brick> = vec![];
     let mut falling: HashSet<Brick> = HashSet::new();
     let mut queue: VecDeque<Brick> = VecDeque::new();
@@ -134,6 +140,7 @@ fn find_resting_positions(bricks: &[Brick]) -> Vec<Brick> {
     falling.extend(bricks.iter());
     queue.extend(bricks.iter());
     while let Some(brick) = queue.pop_front() {
+        if cancelled() { return None; }
         falling.remove(&brick);
 
         // brick has come to rest on the ground or on some other brick
@@ -150,7 +157,7 @@ fn find_resting_positions(bricks: &[Brick]) -> Vec<Brick> {
         falling.insert(next);
         queue.push_back(next);
     }
-    at_rest
+    Some(at_rest)
 }
 
 
@@ -163,7 +170,7 @@ fn calc_support_map(bricks: &[Brick]) -> HashMap<Brick, HashSet<Brick>> {
                 *brick,  // k
                 bricks.iter()  // v
                     .filter(|b| brick.rests_on(b))
-                    .map(|b| b.clone())
+                    .copied()
                     .collect()
             )
         )
@@ -195,6 +202,6 @@ fn remove_bricks(map: &HashMap<Brick, HashSet<Brick>>, bricks: &[&Brick]) -> Has
 }
 
 
-fn load_bricks(content: &str) -> Vec<Brick> {
-    content.split("\n").map(Brick::from_string).collect()
+fn load_bricks(content: &str) -> Result<Vec<Brick>> {
+    parse_lines(content, Brick::from_string)
 }
\ No newline at end of file
@@ -4,35 +4,49 @@ use std::collections::{HashSet, VecDeque, HashMap};
 
 use itertools::Itertools;
 
-use crate::utils::{Grid, Point};
-
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content);
+use crate::answer::Answer;
+use crate::cancel::cancelled;
+use crate::error::{AocError, Result};
+use crate::{debug, info};
+use crate::solution::Solution;
+use crate::utils::{Direction, Grid, Neighborhood, Point};
+
+pub struct Day23 {
+    grid: Grid,
 }
 
+impl Solution for Day23 {
+    fn parse(content: &str) -> Result<Day23> {
+        let grid = Grid::from_string(content)?;
+        grid.check_chars(".#^v<>")?;
+        // the path starts in the second column of the top row and ends in the second last
+        // column of the bottom row, which need to be different rows
+        if (grid.nrows < 2) || (grid.ncols < 3) {
+            return Err(AocError::new(format!("expected at least 2 rows and 3 columns, found {} by {}", grid.nrows, grid.ncols)));
+        }
+        Ok(Day23{ grid })
+    }
 
-fn part1(content: &str) {
-    let grid = Grid::from_string(content);
-    println!("PART 1: {}", find_longest_path(&grid));
-}
-
+    fn part1(&self) -> Answer {
+        find_longest_path(&self.grid).map_or(Answer::Unsolved, Answer::from)
+    }
 
-fn part2(content: &str) {
-    let grid = Grid::from_string(content);
-    let graph = make_graph(&grid);
-    println!("PART 2: {}", find_longest_graph_path(&grid, &graph));
+    fn part2(&self) -> Answer {
+        let graph = make_graph(&self.grid);
+        find_longest_graph_path(&self.grid, &graph).map_or(Answer::Unsolved, Answer::from)
+    }
 }
 
 
-/// Find longest path through the maze
-fn find_longest_path(grid: &Grid) -> usize {
+/// Find longest path through the maze, giving up if cancelled
+fn find_longest_path(grid: &Grid) -> Option<usize> {
     let start = Point::new(0, 1);
     let mut queue = VecDeque::new();
     queue.push_back(Path::new(vec![start]));
     let mut longest = 0;
     while let Some(path) = queue.pop_front() {
-        let path = path.move_to_decision(&grid);
+        if cancelled() { return None; }
+        let path = path.move_to_decision(grid);
         if path.finished(grid) && path.length() > longest {
             longest = path.length();
         }
@@ -40,29 +54,30 @@ fn find_longest_path(grid: &Grid) -> usize {
             queue.push_back(path.step(next));
         }
     }
-    longest
+    Some(longest)
 }
 
 
-/// Find longest path through the graph
-fn find_longest_graph_path(grid: &Grid, graph: &HashMap<Point, HashMap<Point, usize>>) -> usize {
+/// Find longest path through the graph, giving up if cancelled
+fn find_longest_graph_path(grid: &Grid, graph: &HashMap<Point, HashMap<Point, usize>>) -> Option<usize> {
     let start = Point::new(0, 1);
     let mut queue = VecDeque::new();
     queue.push_back(GraphPath::new(start));
     let mut longest = 0;
     while let Some(path) = queue.pop_front() {
-        if path.finished(grid) { println!("{}", path.length); }
+        if cancelled() { return None; }
+        if path.finished(grid) { debug!("finished a path of length {}", path.length); }
         if path.finished(grid) && path.length > longest {
             longest = path.length;
-            println!("{} <----", path.length);
+            info!("longest path so far {}", path.length);
         }
         for (nbr, edge) in graph[&path.node].iter() {
-            if !path.set.contains(&nbr) {
+            if !path.set.contains(nbr) {
                 queue.push_back(path.step(nbr, *edge))
             }
         }
     }
-    longest
+    Some(longest)
 }
 
 
@@ -96,35 +111,12 @@ impl Path {
     /// What moves can we make?
     fn valid_moves(&self, grid: &Grid) -> Vec<Point> {
         let current = *self.steps.iter().last().unwrap();
-        let mut moves = vec![];
-        // up
-        if current.r > 0 {
-            let next = current.up(1);
-            let c = *grid.get(&next);
-            if (c == '.') || (c == '^') { moves.push(next); }
-        }
-        // down
-        if current.r < grid.nrows - 1 {
-            let next = current.down(1);
-            let c = *grid.get(&next);
-            if (c == '.') || (c == 'v') { moves.push(next); }
-        }
-        // left
-        if current.c > 0 {
-            let next = current.left(1);
-            let c = *grid.get(&next);
-            if (c == '.') || (c == '<') { moves.push(next); }
-        }
-        // right
-        if current.c < grid.ncols - 1 {
-            let next = current.right(1);
-            let c = *grid.get(&next);
-            if (c == '.') || (c == '>') { moves.push(next); }
-        }
-        moves
+        // paths are fine, but slopes can only be gone down
+        Direction::ORTHOGONAL
             .iter()
+            .filter_map(|dir| grid.look(&current, *dir).filter(|(_, c)| (**c == '.') || (Direction::from_arrow(**c) == Some(*dir))))
+            .map(|(next, _)| next)
             .filter(|p| !self.set.contains(p))
-            .map(|p| *p)
             .collect_vec()
     }
 
@@ -207,9 +199,8 @@ fn is_node(grid: &Grid, point: &Point) -> bool {
     if *grid.get(point) == '#' {
         return false;
     }
-    let n_edges = point.direct_neighbors(grid)
-        .iter()
-        .filter(|p| *grid.get(p) != '#')
+    let n_edges = grid.neighbors(point, Neighborhood::Orthogonal)
+        .filter(|(_, cell)| **cell != '#')
         .count();
     n_edges != 2
 }
@@ -238,18 +229,8 @@ fn walk(grid: &Grid, node: &Point, next: &Point) -> (Point, usize) {
 
 /// Valid next steps
 fn next_steps(grid: &Grid, point: &Point) -> Vec<Point> {
-    point.direct_neighbors(grid)
-        .iter()
-        .filter(|p| *grid.get(p) != '#')
-        .map(|p| *p)
+    grid.neighbors(point, Neighborhood::Orthogonal)
+        .filter(|(_, cell)| **cell != '#')
+        .map(|(p, _)| p)
         .collect()
-}
-
-
-fn _show(grid: &Grid, path: &Path) {
-    let mut grid = grid.clone();
-    for p in path.set.iter() {
-        grid.cells[p.r][p.c] = 'o';
-    }
-    println!("{}", grid.to_string());
 }
\ No newline at end of file
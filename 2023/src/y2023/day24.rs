@@ -0,0 +1,153 @@
+// 1.5 hours for part 1
+
+use itertools::Itertools;
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, AocError, Result};
+use crate::params::Param;
+use crate::solution::{Check, Solution};
+
+pub struct Day24 {
+    hailstones: Vec<Hailstone>,
+}
+
+impl Solution for Day24 {
+    fn parse(content: &str) -> Result<Day24> {
+        Ok(Day24{ hailstones: load_hailstones(content)? })
+    }
+
+    fn part1(&self) -> Answer {
+        let hailstones = &self.hailstones;
+        let (min, max) = self.test_area();
+        let count =
+            hailstones.iter().enumerate()
+                .cartesian_product(hailstones.iter().enumerate())
+                .filter(|(h1, h2)| h1.0 < h2.0)
+                .map(|(h1, h2)| h1.1.future_xy_crossing(h2.1))
+                .filter(|xy| is_in_box(*xy, min, max))
+                .count();
+        Answer::from(count)
+    }
+
+    fn part2(&self) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![AREA_MIN, AREA_MAX]
+    }
+
+    /// The test area isn't part of the input, so it's picked by guessing whether this is the example
+    fn checks(&self) -> Vec<Check> {
+        let example = self.hailstones.len() < 100;
+        let small = self.hailstones.iter().all(|h| h.px.abs().max(h.py.abs()) < 1e6);
+        vec![
+            Check::new("under 100 hailstones means the example", example == small, || {
+                format!(
+                    "{} hailstones, but their positions are {} the example's",
+                    self.hailstones.len(), if small { "on the scale of" } else { "far bigger than" },
+                )
+            }),
+        ]
+    }
+}
+
+
+const AREA_MIN: Param = Param::new("area_min", 200_000_000_000_000, "lowest x and y of the test area in part 1 (7 for the example)");
+const AREA_MAX: Param = Param::new("area_max", 400_000_000_000_000, "highest x and y of the test area in part 1 (27 for the example)");
+
+
+impl Day24 {
+    /// Area to look for crossings in, which is much smaller for the example
+    fn test_area(&self) -> (f64, f64) {
+        let (min, max) = if self.hailstones.len() < 100 {
+            (7, 27)
+        } else {
+            (AREA_MIN.default, AREA_MAX.default)
+        };
+        (AREA_MIN.given().unwrap_or(min) as f64, AREA_MAX.given().unwrap_or(max) as f64)
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+struct Hailstone {
+    px: f64,
+    py: f64,
+    _pz: f64,
+    vx: f64,
+    vy: f64,
+    _vz: f64,
+}
+
+impl Hailstone {
+    /// Instantiate hailstone
+    fn new(px: f64, py: f64, pz: f64, vx: f64, vy: f64, vz: f64) -> Hailstone {
+        Hailstone{ px, py, _pz: pz, vx, vy, _vz: vz }
+    }
+
+    /// Return location of future crossing...if there is a future crossing
+    fn future_xy_crossing(&self, other: &Hailstone) -> Option<(f64, f64)> {
+        // gradients
+        let m1 = self.vy / self.vx;
+        let m2 = other.vy / other.vx;
+        // trajectories run parallel and in same direction
+        // NOTE: ...one could still overtake the other, so not quite right here!
+        if (m1 - m2).abs() < 1e-12 {
+            return None;
+        }
+        // offsets
+        let c1 = self.py - m1 * self.px;
+        let c2 = other.py - m2 * other.px;
+        // crossover
+        let x = (c2 - c1) / (m1 - m2);
+        let y = m1 * x + c1;
+        // was it in the past?
+        if self.is_xy_in_past(x, y) || other.is_xy_in_past(x, y) {
+            return None;
+        }
+        // otherwise return crossover
+        Some((x, y))
+    }
+
+    fn is_xy_in_past(&self, px: f64, py: f64) -> bool {
+        let xdiff = px - self.px;
+        let ydiff = py - self.py;
+        ((xdiff > 0.0) && self.vx < 0.0) ||
+        ((xdiff < 0.0) && self.vx > 0.0) ||
+        ((ydiff > 0.0) && self.vy < 0.0) ||
+        ((ydiff < 0.0) && self.vy > 0.0)
+    }
+
+}
+
+
+// Is this (optional) xy position in the box?
+fn is_in_box(xy: Option<(f64, f64)>, min: f64, max: f64) -> bool {
+    match xy {
+        Some(xy) => {
+            (xy.0 >= min) &&
+            (xy.0 <= max) &&
+            (xy.1 >= min) &&
+            (xy.1 <= max)
+        },
+        _ => false,
+    }
+}
+
+
+fn load_hailstones(content: &str) -> Result<Vec<Hailstone>> {
+    parse_lines(
+        content,
+        |line| {
+            let parts: Vec<f64> = line.split_whitespace()
+                .filter(|x| *x != "@")
+                .map(|x| parse_num(x.trim_end_matches(",")))
+                .collect::<Result<_>>()?;
+            if parts.len() != 6 {
+                return Err(AocError::invalid("hailstone", line));
+            }
+            Ok(Hailstone::new(parts[0], parts[1], parts[2], parts[3], parts[4], parts[5]))
+        }
+    )
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+// ...
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::Solution;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    fn parse(_content: &str) -> Result<Day25> {
+        Ok(Day25)
+    }
+
+    fn part1(&self) -> Answer {
+        Answer::Unsolved
+    }
+
+    fn part2(&self) -> Answer {
+        Answer::Unsolved
+    }
+}
\ No newline at end of file
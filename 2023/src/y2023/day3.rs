@@ -0,0 +1,103 @@
+// Yeesh, took 1.5 hours!
+
+use std::collections::{HashMap, HashSet};
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solution::Solution;
+use crate::utils::{Grid, Neighborhood, Point};
+
+
+pub struct Day3 {
+    grid: Grid,
+}
+
+impl Solution for Day3 {
+    fn parse(content: &str) -> Result<Day3> {
+        Ok(Day3{ grid: Grid::from_string(content)? })
+    }
+
+    fn part1(&self) -> Answer {
+        let grid = &self.grid;
+        let mut part_sum = 0;
+        for (row, line) in grid.rows().enumerate() {
+            let mut buffer = 0;
+            for (col, char) in line.iter().enumerate() {
+                if char.is_ascii_digit() {
+                    buffer = 10 * buffer + char.to_digit(10).unwrap();
+                } else if buffer > 0 {
+                    let size = buffer.to_string().len();
+                    if is_near_symbol(grid, row, col - size, col - 1) {
+                        part_sum += buffer;
+                    };
+                    buffer = 0;
+                } else {
+                    buffer = 0;
+                }
+            }
+            if buffer > 0 {
+                let size = buffer.to_string().len();
+                if is_near_symbol(grid, row, line.len() - size, line.len() - 1) {
+                    part_sum += buffer;
+                }
+          }
+        }
+        Answer::from(part_sum)
+    }
+
+    fn part2(&self) -> Answer {
+        let grid = &self.grid;
+        let mut gearmap: HashMap<Point, Vec<u32>> = HashMap::new();
+
+        let mut buffer: u32 = 0;
+        for (row, line) in grid.rows().enumerate() {
+            for (col, char) in line.iter().enumerate() {
+                if char.is_ascii_digit() {
+                    buffer = 10 * buffer + char.to_digit(10).unwrap();
+                }
+                let next_col = col + 1;
+                let finished = if next_col == grid.ncols { true } else { !grid.row(row)[next_col].is_ascii_digit() };
+                if finished & (buffer > 0) {
+                    let size = buffer.to_string().len();
+                    let gears: HashSet<Point> = (col + 1 - size..=col)
+                        .flat_map(|c| grid.neighbors(&Point::new(row, c), Neighborhood::All))
+                        .filter(|(_, cell)| **cell == '*')
+                        .map(|(p, _)| p)
+                        .collect();
+                    for gear in gears {
+                        gearmap
+                            .entry(gear)
+                            .and_modify(|v| v.push(buffer))
+                            .or_insert(vec![buffer]);
+                    }
+                    buffer = 0;
+                }
+            }
+        }
+        let gearratios: u32 = 
+            gearmap.values()
+            .filter(|vals| vals.len() == 2)
+            .map(|vals| vals[0] * vals[1])
+            .sum();
+
+        Answer::from(gearratios)
+    }
+}
+
+
+fn is_near_symbol(grid: &Grid, row: usize, c1: usize, c2: usize) -> bool {
+  (c1..=c2).any(|c| {
+      grid.neighbors(&Point::new(row, c), Neighborhood::All)
+          .any(|(_, cell)| is_symbol(cell))
+  })
+}
+
+
+fn is_symbol(char: &char) -> bool {
+  let syms = ['$', '#', '&', '%', '@', '+', '-', '*', '/', '='];
+  for sym in syms {
+      if sym == *char {
+          return true;
+      }
+  }
+  false
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+// 25 mins for part 1, 35 for part 2
+
+use std::collections::HashSet;
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, split_pair, Result};
+use crate::solution::Solution;
+
+pub struct Day4 {
+    cards: Vec<(Vec<u32>, Vec<u32>)>,
+}
+
+impl Solution for Day4 {
+    fn parse(content: &str) -> Result<Day4> {
+        Ok(Day4{ cards: parse_lines(content, parse_card)? })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut total = 0;
+        for (winning, mine) in self.cards.iter() {
+            let matching = count_matching(winning, mine);
+            let score = calc_score(&matching);
+            total += score;
+        }
+        Answer::from(total)
+    }
+
+    fn part2(&self) -> Answer {
+        let mut counts: Vec<u32> = vec![1; self.cards.len()];
+        for (i, (winning, mine)) in self.cards.iter().enumerate() {
+            let n: usize = count_matching(winning, mine).try_into().unwrap();
+            // copies are never won past the end of the table
+            for j in (i + 1)..(i + n + 1).min(counts.len()) {
+                counts[j] += counts[i];
+            }
+        }
+        Answer::from(counts.iter().sum::<u32>())
+    }
+}
+
+
+fn count_matching(winning: &[u32], mine: &[u32]) -> u32 {
+    let winning: HashSet<&u32> = HashSet::from_iter(winning);    
+    mine.iter()
+        .filter(|v| winning.contains(*v))
+        .collect::<Vec<&u32>>()
+        .len()
+        .try_into()
+        .unwrap()
+}
+
+
+fn calc_score(matching: &u32) -> u32 {
+    let base: u32 = 2;
+    if *matching == 0 { 0 } else { base.pow(*matching - 1) }
+}
+
+
+fn parse_card(line: &str) -> Result<(Vec<u32>, Vec<u32>)> {
+    let (_, hands) = split_pair(line.trim(), ": ")?;
+    let (winning, mine) = split_pair(hands, " | ")?;
+    Ok((to_numbers(winning)?, to_numbers(mine)?))
+}
+
+
+fn to_numbers(line: &str) -> Result<Vec<u32>> {
+    line.split_whitespace()
+        .map(parse_num)
+        .collect()
+}
\ No newline at end of file
@@ -1,51 +1,57 @@
 // 1 hour for part 1 ...3+ hours for part 2!
 
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content);
+use crate::answer::Answer;
+use crate::error::{parse_num, split_pair, AocError, Result};
+use crate::solution::Solution;
+
+pub struct Day5 {
+    seeds: Vec<usize>,
+    maps: Vec<Map>,
 }
 
+impl Solution for Day5 {
+    fn parse(content: &str) -> Result<Day5> {
+        let (seeds, maps) = parse_content(content)?;
+        Ok(Day5{ seeds, maps })
+    }
 
-fn part1(content: &str) {
-    let (seeds, maps) = parse_content(content);
-    let mut min_id: usize = 0;
-    for seed in seeds {
-        let mut id = seed.clone();
-        for map in &maps {
-            id = map.forward(id);
+    fn part1(&self) -> Answer {
+        let mut min_id: usize = 0;
+        for seed in self.seeds.iter() {
+            let mut id = *seed;
+            for map in &self.maps {
+                id = map.forward(id);
+            }
+            if (min_id == 0) | (id < min_id) { min_id = id };
         }
-        if (min_id == 0) | (id < min_id) { min_id = id };
+        Answer::from(min_id)
     }
-    println!("PART 1: {}", min_id);
-}
 
-
-fn part2(content: &str) {
-    let (seeds, maps_list) = parse_content(content);
-
-    // convert seeds to ranges
-    let seeds: Vec<Range> = 
-        seeds.chunks(2)
-        .map(|x| Range::new(x[0], x[0] + x[1]))
-        .collect();
-
-    // determine an end to end map from seed to location
-    let mut end_to_end_map = maps_list[0].clone();
-    for map in maps_list[1..].iter() {
-        end_to_end_map = Map::collapse(&end_to_end_map, &map);
-    }
-    end_to_end_map.sort();
-
-    // traverse up the destinations till we hit a seed!
-    for range in end_to_end_map.ranges.iter() {
-        for val in range.source.start..range.source.end {
-            for chunk in seeds.iter() {
-                if chunk.contains(&val) {
-                    println!("PART 2: {}", end_to_end_map.forward(val));
-                    return;
+    fn part2(&self) -> Answer {
+        // convert seeds to ranges
+        let seeds: Vec<Range> =
+            self.seeds.chunks(2)
+            .map(|x| Range::new(x[0], x[0] + x[1]))
+            .collect();
+
+        // determine an end to end map from seed to location
+        let mut end_to_end_map = self.maps[0].clone();
+        for map in self.maps[1..].iter() {
+            end_to_end_map = Map::collapse(&end_to_end_map, map);
+        }
+        end_to_end_map.sort();
+
+        // traverse up the destinations till we hit a seed!
+        for range in end_to_end_map.ranges.iter() {
+            for val in range.source.start..range.source.end {
+                for chunk in seeds.iter() {
+                    if chunk.contains(&val) {
+                        return Answer::from(end_to_end_map.forward(val));
+                    }
                 }
             }
         }
+        Answer::Unsolved
     }
 }
 
@@ -127,7 +133,7 @@ impl Map {
     }
 
     fn sort(&mut self) {
-        self.ranges.sort_by(|a, b| a.dest.start.cmp(&b.dest.start))
+        self.ranges.sort_by_key(|a| a.dest.start)
     }
 
     fn source_boundaries(&self) -> Vec<usize> {
@@ -154,27 +160,33 @@ impl Map {
 }
 
 
-fn parse_content(content: &str) -> (Vec<usize>, Vec<Map>) {
+fn parse_content(content: &str) -> Result<(Vec<usize>, Vec<Map>)> {
     let lines: Vec<&str> =
         content.split("\n")
         .map(|line| line.trim())
         .collect();
-    let seeds: Vec<usize> = 
-        lines[0].split(": ")
-        .last()
-        .unwrap()
-        .split(" ")
-        .map(|v| v.parse().unwrap())
-        .collect();
+    let (_, seeds) = split_pair(lines[0], ": ").map_err(|e| e.on_line(0, lines[0]))?;
+    let seeds: Vec<usize> =
+        seeds.split(" ")
+        .map(parse_num)
+        .collect::<Result<_>>()
+        .map_err(|e| e.on_line(0, lines[0]))?;
+    if seeds.len() % 2 == 1 {
+        return Err(AocError::new("expected pairs of seeds").at(0, 0));
+    }
     let mut maps: Vec<Map> = vec![];
     let mut buffer: Vec<RangeMap> = vec![];
     for (i, line) in lines.iter().enumerate() {
         if (i < 3) | (line == &"") { continue };
-        if line.chars().nth(0).unwrap().is_digit(10) {
+        if line.starts_with(|c: char| c.is_ascii_digit()) {
             let parts: Vec<usize> =
                 line.split(" ")
-                .map(|v| v.parse().unwrap())
-                .collect();
+                .map(parse_num)
+                .collect::<Result<_>>()
+                .map_err(|e| e.on_line(i, line))?;
+            if parts.len() != 3 {
+                return Err(AocError::new("expected 3 numbers").at(i, 0));
+            }
             buffer.push(RangeMap::new(parts));
         } else {
             maps.push(Map::new(buffer));
@@ -182,5 +194,5 @@ fn parse_content(content: &str) -> (Vec<usize>, Vec<Map>) {
         }
     }
     maps.push(Map::new(buffer));
-    (seeds, maps)
+    Ok((seeds, maps))
 }
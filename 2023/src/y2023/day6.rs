@@ -0,0 +1,89 @@
+// 40 mins for part 1 ...then an hour to figure out how to go beyond f64 for part 2!
+use rug::{Float, Integer};
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, split_pair, AocError, Result};
+use crate::solution::Solution;
+
+pub struct Day6 {
+    races: (Vec<i64>, Vec<i64>),
+    race: (i64, i64),
+}
+
+impl Solution for Day6 {
+    fn parse(content: &str) -> Result<Day6> {
+        Ok(Day6{ races: parse_part1(content)?, race: parse_part2(content)? })
+    }
+
+    fn part1(&self) -> Answer {
+        let (times, records) = &self.races;
+        let solution: Integer =
+            times.iter()
+            .zip(records.iter())
+            .map(|x| find_ways_to_beat(x.0, x.1))
+            .product();
+        Answer::from(solution)
+    }
+
+    fn part2(&self) -> Answer {
+        let (time, record) = self.race;
+        Answer::from(find_ways_to_beat(&time, &record))
+    }
+}
+
+
+fn find_ways_to_beat(time: &i64, record: &i64) -> Integer {
+    let time = Float::with_val(128, *time);
+    let record = Float::with_val(128, *record);
+    let (lower, upper) = solve_quadratic(
+        Float::with_val(128, 1.0), -time, record);
+    let mut lower_ceil = lower.clone().ceil().to_integer().unwrap();
+    let upper_ceil = upper.ceil().to_integer().unwrap();
+    if (lower - &lower_ceil).abs() < 0.0001 {
+        lower_ceil += 1;
+    }
+    upper_ceil - lower_ceil
+}
+
+
+fn solve_quadratic(a: Float, b: Float, c: Float) -> (Float, Float) {
+    let b_ = b.clone();
+    let s: Float = (b_ * &b - Float::with_val(128, 4.0) * &a * &c).sqrt();
+    let den: Float = Float::with_val(128, 2.0) * &a;
+
+    let b_ = b.clone();
+    let lower = (-b_ - &s) / &den;
+    let upper = (-b + &s) / &den;
+    (lower, upper)
+}
+
+
+fn parse_part1(content: &str) -> Result<(Vec<i64>, Vec<i64>)> {
+    let lines: Vec<Vec<i64>> = parse_lines(content, |line| {
+        let (_, values) = split_pair(line, ":")?;
+        values.split_whitespace().map(parse_num).collect()
+    })?;
+    check_two_lines(&lines)?;
+    if lines[0].len() != lines[1].len() {
+        return Err(AocError::new("expected a distance for every time").at(1, 0));
+    }
+    Ok((lines[0].clone(), lines[1].clone()))
+}
+
+
+fn parse_part2(content: &str) -> Result<(i64, i64)> {
+    let lines: Vec<i64> = parse_lines(content, |line| {
+        let (_, values) = split_pair(line, ":")?;
+        parse_num(&values.split_whitespace().collect::<String>())
+    })?;
+    check_two_lines(&lines)?;
+    Ok((lines[0], lines[1]))
+}
+
+
+fn check_two_lines<T>(lines: &[T]) -> Result<()> {
+    match lines.len() {
+        2 => Ok(()),
+        n => Err(AocError::new(format!("expected times and distances, found {} lines", n))),
+    }
+}
\ No newline at end of file
@@ -2,27 +2,34 @@
 
 use std::collections::HashMap;
 
-pub fn run(content: String) {
-    part1(&content);
-    part2(&content);
-}
-
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, split_pair, AocError, Result};
+use crate::solution::Solution;
 
-fn part1(content: &str) {
-    let mut hands = to_hands(content);
-    hands.sort_by_cached_key(|x| x.0.sort_key());
-    println!("PART 1: {}", calc_winnings(hands));
+pub struct Day7 {
+    hands: Vec<(Hand, usize)>,
 }
 
+impl Solution for Day7 {
+    fn parse(content: &str) -> Result<Day7> {
+        Ok(Day7{ hands: to_hands(content)? })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut hands = self.hands.clone();
+        hands.sort_by_cached_key(|x| x.0.sort_key());
+        Answer::from(calc_winnings(hands))
+    }
 
-fn part2(content: &str) {
-    let mut hands = to_hands(content);
-    hands.sort_by_cached_key(|x| x.0.joker_sort_key());
-    println!("PART 2: {}", calc_winnings(hands));
+    fn part2(&self) -> Answer {
+        let mut hands = self.hands.clone();
+        hands.sort_by_cached_key(|x| x.0.joker_sort_key());
+        Answer::from(calc_winnings(hands))
+    }
 }
 
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Hand {
     cards: String
 }
@@ -66,15 +73,15 @@ impl Hand {
         vals.reverse();
 
         if *vals[0] + nj == 5 {
-            return 6;  // can make 5 of a kind
+            6// can make 5 of a kind
         } else if *vals[0] + nj == 4 {
-            return 5;  // can make 4 of a kind
+            5// can make 4 of a kind
         } else if vals == vec![&2, &2] {
-            return 4;  // can make full house
+            4// can make full house
         } else if (vals == vec![&2, &1, &1]) | (vals == vec![&1, &1, &1]) {
-            return 3;  // can make 3 of a kind
+            3// can make 3 of a kind
         } else {
-            return 1;  // can always make a pair
+            1// can always make a pair
         }
     }
 
@@ -84,19 +91,19 @@ impl Hand {
         counts.reverse();
 
         if *counts[0] == 5 {
-            return 6;  // 5 of a kind
+            6// 5 of a kind
         } else if *counts[0] == 4 {
-            return 5;  // 4 of a kind
+            5// 4 of a kind
         } else if (*counts[0] == 3) & (*counts[1] == 2) {
-            return 4;  // full house
+            4// full house
         } else if *counts[0] == 3 {
-            return 3;  // 3 of a kind
+            3// 3 of a kind
         } else if (*counts[0] == 2) & (*counts[1] == 2) {
-            return 2;  // 2 pairs
+            2// 2 pairs
         } else if *counts[0] == 2 {
-            return 1;  // 1 pair
+            1// 1 pair
         } else {
-            return 0;
+            0
         }
     }
 
@@ -126,13 +133,12 @@ fn calc_winnings(hands: Vec<(Hand, usize)>) -> usize {
 }
 
 
-fn to_hands(content: &str) -> Vec<(Hand, usize)> {
-    let mut hands: Vec<(Hand, usize)> = vec![];
-    for line in content.split("\n").map(|line| line.trim()) {
-        let parts = line.split_whitespace().collect::<Vec<&str>>();
-        let hand = Hand::new(String::from(parts[0]));
-        let bid: usize = parts[1].parse().unwrap();
-        hands.push((hand, bid))
-    }
-    hands
+fn to_hands(content: &str) -> Result<Vec<(Hand, usize)>> {
+    parse_lines(content, |line| {
+        let (cards, bid) = split_pair(line.trim(), " ")?;
+        if (cards.len() != 5) || !cards.chars().all(|c| "AKQJT98765432".contains(c)) {
+            return Err(AocError::invalid("hand", cards));
+        }
+        Ok((Hand::new(String::from(cards)), parse_num(bid)?))
+    })
 }
\ No newline at end of file
@@ -0,0 +1,182 @@
+// 30 mins part 1, 45 mins part 2
+
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use primes::factors;
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, split_pair, AocError, Result};
+use crate::solution::{Check, Solution};
+
+pub struct Day8 {
+    moves: Moves,
+    graph: Graph,
+}
+
+impl Solution for Day8 {
+    fn parse(content: &str) -> Result<Day8> {
+        let (moves, graph) = parse_content(content)?;
+        Ok(Day8{ moves, graph })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut moves = self.moves.clone();
+        let graph = &self.graph;
+        // part 2's examples don't have these
+        if !graph.map.contains_key("AAA") || !graph.map.contains_key("ZZZ") {
+            return Answer::Unsolved;
+        }
+        let mut node = &String::from("AAA");
+        let mut step = 0;
+        loop {
+            if *node == "ZZZ" { break }
+            let dir = moves.next();
+            let (left, right) = &graph.map[node];
+            node = if *dir == 'L' { left } else { right };
+            step += 1;
+        }
+        Answer::from(step)
+    }
+
+    fn part2(&self) -> Answer {
+        let graph = &self.graph;
+        let nodes: Vec<&String> =
+            graph.map.keys()
+            .filter(|k| k.chars().nth(2).unwrap() == 'A')
+            .collect();
+        let mut factset: HashSet<u64> = HashSet::new();
+        for node in nodes {
+            // every ghost starts from the beginning of the moves
+            let mut moves = self.moves.clone();
+            let mut node = node;
+            let mut step: u64 = 0;
+            loop {
+                if node.chars().nth(2).unwrap() == 'Z' {
+                    break
+                }
+                let dir = moves.next();
+                let (left, right) = &graph.map[node];
+                node = if *dir == 'L' { left } else { right };
+                step += 1;
+            }
+            factset.extend(factors(step).iter());
+        }
+        Answer::from(factset.iter().product::<u64>())
+    }
+
+    /// Part 2 takes the steps for each ghost to reach a Z as its cycle length, which is only
+    /// right if it then goes round to the same Z in the same number of steps
+    fn checks(&self) -> Vec<Check> {
+        let limit = self.moves.moves.len() * self.graph.map.len();
+        let mut problems = vec![];
+        for start in self.graph.map.keys().filter(|k| k.ends_with('A')).sorted() {
+            let mut moves = self.moves.clone();
+            let Some((end, steps)) = walk_to_z(&self.graph, &mut moves, start, limit) else {
+                problems.push(format!("{} never reaches a Z", start));
+                continue;
+            };
+            match walk_to_z(&self.graph, &mut moves, end, limit) {
+                Some((next, cycle)) if (next == end) && (cycle == steps) => (),
+                Some((next, cycle)) => {
+                    problems.push(format!("{} reaches {} in {} steps, then {} in {}", start, end, steps, next, cycle));
+                },
+                None => problems.push(format!("{} reaches {} in {} steps, then no other Z", start, end, steps)),
+            }
+        }
+        vec![
+            Check::new("each ghost's cycle length is its distance to Z", problems.is_empty(), || problems.join("; ")),
+        ]
+    }
+}
+
+
+/// Follow the moves from a node until reaching one ending in Z (after at least one step), giving
+/// up after `limit` steps. Returns the node reached and the number of steps.
+fn walk_to_z<'a>(graph: &'a Graph, moves: &mut Moves, start: &'a String, limit: usize) -> Option<(&'a String, usize)> {
+    let mut node = start;
+    for step in 1..=limit {
+        let (left, right) = graph.map.get(node)?;
+        node = if *moves.next() == 'L' { left } else { right };
+        if node.ends_with('Z') {
+            return Some((node, step));
+        }
+    }
+    None
+}
+
+
+#[derive(Debug, Clone)]
+struct Moves {
+    moves: Vec<char>,
+    index: usize,
+}
+
+impl Moves {
+    fn new(line: &str) -> Moves {
+        Moves{ moves: line.chars().collect(), index: 0 }
+    }
+
+    fn next(&mut self) -> &char {
+        let next = &self.moves[self.index];
+        self.index += 1;
+        if self.index >= self.moves.len() { self.index = 0 }
+        next
+    }
+}
+
+
+#[derive(Debug)]
+struct Graph {
+    map: HashMap<String, (String, String)>,
+}
+
+impl Graph {
+    fn new(nodes: Vec<(String, (String, String))>) -> Graph {
+        Graph { map: HashMap::from_iter(nodes) }
+    }
+}
+
+
+fn parse_content(content: &str) -> Result<(Moves, Graph)> {
+    let lines: Vec<&str> = content.split("\n").map(|x| x.trim()).collect();
+    if let Some(c) = lines[0].find(|c| (c != 'L') && (c != 'R')) {
+        return Err(AocError::new("moves should only be 'L' or 'R'").at(0, c));
+    }
+    if lines[0].is_empty() {
+        return Err(AocError::new("expected some moves").at(0, 0));
+    }
+    if lines.len() < 3 {
+        return Err(AocError::new("expected moves, a blank line, then the nodes"));
+    }
+    let nodes = parse_lines(&lines[2..].join("\n"), parse_node).map_err(|e| e.below(2))?;
+    check_links(&nodes, &lines[2..]).map_err(|e| e.below(2))?;
+    Ok((Moves::new(lines[0]), Graph::new(nodes)))
+}
+
+
+/// Make sure every node leads somewhere which is defined, pointing at the first which doesn't
+fn check_links(nodes: &[(String, (String, String))], lines: &[&str]) -> Result<()> {
+    let defined: HashSet<&String> = nodes.iter().map(|(node, _)| node).collect();
+    for (i, ((_, (left, right)), line)) in nodes.iter().zip(lines).enumerate() {
+        let left_at = line.find('(').unwrap() + 1;
+        let right_at = left_at + left.len() + 2;
+        for (next, c) in [(left, left_at), (right, right_at)] {
+            if !defined.contains(next) {
+                return Err(AocError::new(format!("node {} isn't defined", next)).at(i, c));
+            }
+        }
+    }
+    Ok(())
+}
+
+
+/// Parse a node like "AAA = (BBB, CCC)"
+fn parse_node(line: &str) -> Result<(String, (String, String))> {
+    let (node, next) = split_pair(line, " = ")?;
+    let next = next
+        .strip_prefix('(')
+        .and_then(|n| n.strip_suffix(')'))
+        .ok_or_else(|| AocError::invalid("node", next))?;
+    let (left, right) = split_pair(next, ", ")?;
+    Ok((String::from(node), (String::from(left), String::from(right))))
+}
\ No newline at end of file
@@ -0,0 +1,87 @@
+// 30 mins total
+
+use crate::answer::Answer;
+use crate::error::{parse_lines, parse_num, Result};
+use crate::solution::Solution;
+
+pub struct Day9 {
+    histories: Vec<Vec<i64>>,
+}
+
+impl Solution for Day9 {
+    fn parse(content: &str) -> Result<Day9> {
+        Ok(Day9{ histories: parse_content(content)? })
+    }
+
+    fn part1(&self) -> Answer {
+        let answer: i64 =
+            self.histories.iter()
+            .map(|h| extrapolate(h))
+            .sum();
+        Answer::from(answer)
+    }
+
+    fn part2(&self) -> Answer {
+        let answer: i64 =
+            self.histories.iter()
+            .map(|h| baxtrapolate(h))
+            .sum();
+        Answer::from(answer)
+    }
+}
+
+
+fn extrapolate(values: &[i64]) -> i64 {
+    let mut values = values.to_vec();
+    let mut prevs: Vec<i64> = vec![values[values.len() - 1]];
+    loop {
+        values = to_diffs(&values);
+        if all_zeros(&values) { break; }
+        prevs.push(values[values.len() - 1]);
+    }
+    prevs.iter().sum()
+}
+
+
+fn baxtrapolate(values: &[i64]) -> i64 {
+    let mut values = values.to_vec();
+    let mut firsts: Vec<i64> = vec![values[0]];
+    loop {
+        values = to_diffs(&values);
+        if all_zeros(&values) { break; }
+        firsts.push(values[0]);
+    }
+    let mut forecast = 0;
+    for v in firsts.iter().rev() {
+        forecast = v - forecast;
+    }
+    forecast
+}
+
+
+fn to_diffs(values: &[i64]) -> Vec<i64> {
+    values[1..].iter()
+    .enumerate()
+    .map(
+        |iv| iv.1 - values[iv.0]
+    )
+    .collect()
+}
+
+
+fn all_zeros(values: &[i64]) -> bool {
+    values.iter().map(|v| v.abs()).sum::<i64>() == 0
+}
+
+
+fn parse_content(content: &str) -> Result<Vec<Vec<i64>>> {
+    parse_lines(
+        content,
+        |x| {
+            x.trim()
+            .split(" ")
+            .map(parse_num)
+            .collect()
+        }
+    )
+}
\ No newline at end of file
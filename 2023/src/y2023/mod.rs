@@ -0,0 +1,57 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::solution::{boxed, Day};
+
+
+/// Every registered day of the 2023 event, in order
+pub const DAYS: [Day; 25] = [
+    Day::new(2023, 1, boxed::<day1::Day1>),
+    Day::new(2023, 2, boxed::<day2::Day2>),
+    Day::new(2023, 3, boxed::<day3::Day3>),
+    Day::new(2023, 4, boxed::<day4::Day4>),
+    Day::new(2023, 5, boxed::<day5::Day5>),
+    Day::new(2023, 6, boxed::<day6::Day6>),
+    Day::new(2023, 7, boxed::<day7::Day7>),
+    Day::new(2023, 8, boxed::<day8::Day8>),
+    Day::new(2023, 9, boxed::<day9::Day9>),
+    Day::new(2023, 10, boxed::<day10::Day10>),
+    Day::new(2023, 11, boxed::<day11::Day11>),
+    Day::new(2023, 12, boxed::<day12::Day12>),
+    Day::new(2023, 13, boxed::<day13::Day13>),
+    Day::new(2023, 14, boxed::<day14::Day14>),
+    Day::new(2023, 15, boxed::<day15::Day15>),
+    Day::new(2023, 16, boxed::<day16::Day16>),
+    Day::new(2023, 17, boxed::<day17::Day17>),
+    Day::new(2023, 18, boxed::<day18::Day18>),
+    Day::new(2023, 19, boxed::<day19::Day19>),
+    Day::new(2023, 20, boxed::<day20::Day20>),
+    Day::new(2023, 21, boxed::<day21::Day21>),
+    Day::new(2023, 22, boxed::<day22::Day22>),
+    Day::new(2023, 23, boxed::<day23::Day23>),
+    Day::new(2023, 24, boxed::<day24::Day24>),
+    Day::new(2023, 25, boxed::<day25::Day25>),
+];
\ No newline at end of file